@@ -4,7 +4,11 @@ use bevy_mod_picking::DefaultPickingPlugins;
 
 fn main() {
     App::new()
-        .add_plugins((DefaultPlugins, DioxusUiPlugin, DefaultPickingPlugins))
+        .add_plugins((
+            DefaultPlugins,
+            DioxusUiPlugin::default(),
+            DefaultPickingPlugins,
+        ))
         .add_systems(Startup, |mut commands: Commands| {
             commands.spawn(DioxusUiBundle {
                 dioxus_ui_root: DioxusUiRoot(Editor),
@@ -34,11 +38,21 @@ fn Editor() -> Element {
 #[component]
 fn SceneTree(selected_entity: Signal<Option<Entity>, SyncStorage>) -> Element {
     let mut entities = use_query_filtered::<(Entity, DebugName), Without<Node>>();
-    let entities = entities.query();
-    let mut entities = entities.into_iter().collect::<Vec<_>>();
+    let mut entities = entities.query(|entities| {
+        entities
+            .iter()
+            .map(|(entity, name)| {
+                let name = match name.name {
+                    Some(name) => format!("{name}"),
+                    _ => format!("Entity ({:?})", name.entity),
+                };
+                (entity, name)
+            })
+            .collect::<Vec<_>>()
+    });
     entities.sort_by_key(|(entity, _)| *entity);
 
-    let system_scheduler = use_system_scheduler();
+    let commands = use_commands();
 
     rsx! {
         node {
@@ -60,19 +74,13 @@ fn SceneTree(selected_entity: Signal<Option<Entity>, SyncStorage>) -> Element {
                         base_color: if Some(entity) == selected_entity() { Some(VIOLET_700.to_owned()) } else { None },
                         click_color: if Some(entity) == selected_entity() { Some(VIOLET_400.to_owned()) } else { None },
                         hover_color: if Some(entity) == selected_entity() { Some(VIOLET_500.to_owned()) } else { None },
-                        match name.name {
-                            Some(name) => format!("{name}"),
-                            _ => format!("Entity ({:?})", name.entity)
-                        }
+                        "{name}"
                     }
                 }
             }
             Button {
                 onclick: move |event: DioxusEvent<PointerButton>| if *event.data == PointerButton::Primary {
-                    system_scheduler.schedule(move |world: &mut World| {
-                        let new_entity = world.spawn_empty();
-                        selected_entity.set(Some(new_entity.id()));
-                    });
+                    commands.spawn_then((), move |new_entity| selected_entity.set(Some(new_entity)));
                     event.stop_propagation();
                 },
                 text { text: "Spawn Entity", text_size: "18" }
@@ -84,25 +92,29 @@ fn SceneTree(selected_entity: Signal<Option<Entity>, SyncStorage>) -> Element {
 #[component]
 fn EntityInspector(selected_entity: ReadOnlySignal<Option<Entity>, SyncStorage>) -> Element {
     let world = use_world();
-    let type_registry = use_resource::<AppTypeRegistry>().read();
+    let type_registry = use_resource::<AppTypeRegistry>();
     let components = selected_entity()
         .map(|selected_entity| {
-            let entity_ref = world.get_entity(selected_entity).unwrap();
-            let mut components = entity_ref
-                .archetype()
-                .components()
-                .map(|component_id| {
-                    let component_info = world.components().get_info(component_id).unwrap();
-                    let type_info = component_info
-                        .type_id()
-                        .and_then(|type_id| type_registry.get_type_info(type_id));
-                    let (_, name) = component_info.name().rsplit_once("::").unwrap();
-                    let (crate_name, _) = component_info.name().split_once("::").unwrap();
-                    (name, crate_name, type_info)
-                })
-                .collect::<Vec<_>>();
-            components.sort_by_key(|(name, _, _)| *name);
-            components
+            world.with(|world| {
+                let type_registry = type_registry.with(|type_registry| type_registry.clone());
+                let type_registry = type_registry.read();
+                let entity_ref = world.get_entity(selected_entity).unwrap();
+                let mut components = entity_ref
+                    .archetype()
+                    .components()
+                    .map(|component_id| {
+                        let component_info = world.components().get_info(component_id).unwrap();
+                        let type_info = component_info
+                            .type_id()
+                            .and_then(|type_id| type_registry.get_type_info(type_id));
+                        let (_, name) = component_info.name().rsplit_once("::").unwrap();
+                        let (crate_name, _) = component_info.name().split_once("::").unwrap();
+                        (name.to_owned(), crate_name.to_owned(), type_info)
+                    })
+                    .collect::<Vec<_>>();
+                components.sort_by(|(a, _, _), (b, _, _)| a.cmp(b));
+                components
+            })
         })
         .unwrap_or_default();
 
@@ -1,19 +1,29 @@
 use crate::{
+    attribute_values::set_components_attribute,
+    custom_attributes::{ApplyAttribute, CustomAttributes},
+    custom_elements::{CustomElementTag, CustomElements},
+    diagnostics::UiDiagnostic,
+    ecs_hooks::with_world,
     events::{insert_event_listener, remove_event_listener},
-    parse_attributes::set_attribute,
+    parse_attributes::{set_attribute, AttributeInput, AttributeTargets},
+    rounded_boxes::{update_background_color, BorderRadius, BoxShadow, RoundedBox},
+    ui_materials::{UiMaterialTag, UiMaterialType, UiMaterials},
+    ui_transform::UiTransform,
+    DioxusUiRoot,
 };
 use bevy::{
     asset::AssetServer,
     ecs::{
+        component::Component,
         entity::{Entity, EntityHashMap},
         system::Command,
-        world::World,
+        world::{EntityRef, EntityWorldMut, World},
     },
     hierarchy::{BuildWorldChildren, Children, DespawnRecursive, Parent},
     prelude::default,
     render::{color::Color, view::Visibility},
+    sprite::{ImageScaleMode, TextureAtlas},
     text::{Text, TextLayoutInfo, TextStyle},
-    transform::components::Transform,
     ui::{
         node_bundles::{ImageBundle, NodeBundle, TextBundle},
         widget::TextFlags,
@@ -24,12 +34,12 @@ use bevy::{
 use dioxus::dioxus_core::{
     AttributeValue, ElementId, Template, TemplateAttribute, TemplateNode, WriteMutations,
 };
+use std::any::TypeId;
 
 pub struct MutationApplier<'a> {
     element_id_to_bevy_ui_entity: &'a mut HashMap<ElementId, Entity>,
     bevy_ui_entity_to_element_id: &'a mut EntityHashMap<ElementId>,
     templates: &'a mut HashMap<String, BevyTemplate>,
-    world: &'a mut World,
     asset_server: &'a AssetServer,
     stack: Vec<Entity>,
 }
@@ -40,7 +50,6 @@ impl<'a> MutationApplier<'a> {
         bevy_ui_entity_to_element_id: &'a mut EntityHashMap<ElementId>,
         templates: &'a mut HashMap<String, BevyTemplate>,
         root_entity: Entity,
-        world: &'a mut World,
         asset_server: &'a AssetServer,
     ) -> Self {
         element_id_to_bevy_ui_entity.insert(ElementId(0), root_entity);
@@ -50,148 +59,325 @@ impl<'a> MutationApplier<'a> {
             element_id_to_bevy_ui_entity,
             bevy_ui_entity_to_element_id,
             templates,
-            world,
             asset_server,
             stack: vec![root_entity],
         }
     }
 }
 
+impl<'a> MutationApplier<'a> {
+    fn try_set_attribute(
+        &mut self,
+        world: &mut World,
+        name: &'static str,
+        value: &AttributeValue,
+        entity: Entity,
+    ) -> Result<(), String> {
+        if name == "components" {
+            return set_components_attribute(value, &mut world.entity_mut(entity));
+        }
+        if let Some(tag) = world.get::<CustomElementTag>(entity) {
+            let custom_element = world.resource::<CustomElements>()[tag.0];
+            if custom_element.attributes.contains(&name) {
+                return (custom_element.set_attribute)(name, value, &mut world.entity_mut(entity));
+            }
+        }
+
+        let value = match value {
+            AttributeValue::Text(value) => AttributeInput::Text(value),
+            AttributeValue::Float(value) => AttributeInput::Float(*value),
+            AttributeValue::Int(value) => AttributeInput::Int(*value),
+            AttributeValue::Bool(value) => AttributeInput::Bool(*value),
+            AttributeValue::Any(value) => AttributeInput::Any(value.as_any()),
+            AttributeValue::None => {
+                return Err("removing attributes is not yet supported".to_owned());
+            }
+            _ => return Err("unsupported attribute value type".to_owned()),
+        };
+
+        let material = world
+            .get::<UiMaterialTag>(entity)
+            .and_then(|tag| world.resource::<UiMaterials>().get(tag.0))
+            .map(|material| material.handle_type_id);
+        if let Some(custom_attribute) = world.resource::<CustomAttributes>().get(name, material) {
+            let apply = custom_attribute?.parse(value)?;
+            apply(&mut world.entity_mut(entity));
+            return Ok(());
+        }
+
+        let mut image = ImageComponents::from_entity(world, entity);
+        let supports_rounded_box =
+            image.is_none() && !world.entity(entity).contains::<UiMaterialTag>();
+        let (
+            mut style,
+            mut border_color,
+            mut outline,
+            mut border_radius,
+            mut box_shadow,
+            mut rounded_box,
+            mut background_color,
+            mut transform,
+            mut visibility,
+            mut z_index,
+            mut text,
+        ) = world
+            .query::<(
+                &mut Style,
+                &mut BorderColor,
+                &mut Outline,
+                &mut BorderRadius,
+                &mut BoxShadow,
+                &mut RoundedBox,
+                Option<&mut BackgroundColor>,
+                &mut UiTransform,
+                &mut Visibility,
+                &mut ZIndex,
+                Option<&mut Text>,
+            )>()
+            .get_mut(world, entity)
+            .unwrap();
+        // Rounded boxes have no `BackgroundColor` while their material draws them
+        let mut detached_background_color = BackgroundColor(rounded_box.background_color);
+
+        let result = set_attribute(
+            name,
+            value,
+            AttributeTargets {
+                style: &mut style,
+                border_color: &mut border_color,
+                outline: &mut outline,
+                border_radius: &mut border_radius,
+                box_shadow: &mut box_shadow,
+                rounded_box: supports_rounded_box.then_some(&mut *rounded_box),
+                background_color: background_color
+                    .as_deref_mut()
+                    .or(supports_rounded_box.then_some(&mut detached_background_color)),
+                transform: &mut transform,
+                visibility: &mut visibility,
+                z_index: &mut z_index,
+                text: text.as_deref_mut(),
+                image: image.as_mut(),
+            },
+            self.asset_server,
+        );
+
+        if let (Ok(()), Some(image)) = (&result, image) {
+            image.insert(&mut world.entity_mut(entity));
+        }
+        if supports_rounded_box {
+            update_background_color(&mut world.entity_mut(entity));
+        }
+        result
+    }
+
+    fn element_path(&self, world: &World, entity: Entity) -> String {
+        let mut tags = Vec::new();
+        let mut current = Some(entity);
+        while let Some(entity) = current {
+            let entity = world.entity(entity);
+            if entity.contains::<DioxusUiRoot>() {
+                break;
+            }
+            tags.push(element_tag(&entity));
+            current = entity.get::<Parent>().map(Parent::get);
+        }
+        tags.reverse();
+        tags.join(" > ")
+    }
+}
+
+/// Paths are relative to the UI root at runtime, but only to the template's root when parsing
+/// templates, since templates can be placed anywhere.
+fn child_path(parent_path: &str, tag: &str) -> String {
+    if parent_path.is_empty() {
+        tag.to_owned()
+    } else {
+        format!("{parent_path} > {tag}")
+    }
+}
+
+fn element_tag(entity: &EntityRef) -> &'static str {
+    if let Some(CustomElementTag(tag)) = entity.get() {
+        tag
+    } else if entity.contains::<UiMaterialTag>() {
+        "material_node"
+    } else if entity.contains::<UiImage>() {
+        "image"
+    } else if entity.contains::<Text>() {
+        "text"
+    } else {
+        "node"
+    }
+}
+
 impl<'a> WriteMutations for MutationApplier<'a> {
     fn register_template(&mut self, template: Template) {
-        self.templates.insert(
-            template.name.to_owned(),
-            BevyTemplate::from_dioxus(&template, self.asset_server),
-        );
+        with_world(|world| {
+            let mut context = TemplateContext {
+                asset_server: self.asset_server,
+                custom_elements: world.resource(),
+                custom_attributes: world.resource(),
+                ui_materials: world.resource(),
+                diagnostics: Vec::new(),
+            };
+            let bevy_template = BevyTemplate::from_dioxus(&template, &mut context);
+            let diagnostics = context.diagnostics;
+
+            self.templates
+                .insert(template.name.to_owned(), bevy_template);
+            for diagnostic in diagnostics {
+                diagnostic.report(world);
+            }
+        });
     }
 
     fn append_children(&mut self, id: ElementId, m: usize) {
-        let mut parent = self
-            .world
-            .entity_mut(self.element_id_to_bevy_ui_entity[&id]);
-        for child in self.stack.drain((self.stack.len() - m)..) {
-            parent.add_child(child);
-        }
+        with_world(|world| {
+            let mut parent = world.entity_mut(self.element_id_to_bevy_ui_entity[&id]);
+            for child in self.stack.drain((self.stack.len() - m)..) {
+                parent.add_child(child);
+            }
+        });
     }
 
     fn assign_node_id(&mut self, path: &'static [u8], id: ElementId) {
-        let mut entity = *self.stack.last().unwrap();
-        for index in path {
-            entity = self.world.entity(entity).get::<Children>().unwrap()[*index as usize];
-        }
-        self.element_id_to_bevy_ui_entity.insert(id, entity);
-        self.bevy_ui_entity_to_element_id.insert(entity, id);
+        with_world(|world| {
+            let mut entity = *self.stack.last().unwrap();
+            for index in path {
+                entity = world.entity(entity).get::<Children>().unwrap()[*index as usize];
+            }
+            self.element_id_to_bevy_ui_entity.insert(id, entity);
+            self.bevy_ui_entity_to_element_id.insert(entity, id);
+        });
     }
 
     fn create_placeholder(&mut self, id: ElementId) {
-        let entity = self.world.spawn(NodeBundle::default()).id();
-        self.element_id_to_bevy_ui_entity.insert(id, entity);
-        self.bevy_ui_entity_to_element_id.insert(entity, id);
-        self.stack.push(entity);
+        with_world(|world| {
+            let entity = world.spawn(NodeBundle::default()).id();
+            self.element_id_to_bevy_ui_entity.insert(id, entity);
+            self.bevy_ui_entity_to_element_id.insert(entity, id);
+            self.stack.push(entity);
+        });
     }
 
     fn create_text_node(&mut self, value: &str, id: ElementId) {
-        let entity =
-            BevyTemplateNode::IntrinsicTextNode(Text::from_section(value, TextStyle::default()))
-                .spawn(self.world);
-        self.element_id_to_bevy_ui_entity.insert(id, entity);
-        self.bevy_ui_entity_to_element_id.insert(entity, id);
-        self.stack.push(entity);
+        with_world(|world| {
+            let entity = BevyTemplateNode::IntrinsicTextNode(Text::from_section(
+                value,
+                TextStyle::default(),
+            ))
+            .spawn(world);
+            self.element_id_to_bevy_ui_entity.insert(id, entity);
+            self.bevy_ui_entity_to_element_id.insert(entity, id);
+            self.stack.push(entity);
+        });
     }
 
     fn hydrate_text_node(&mut self, path: &'static [u8], value: &str, id: ElementId) {
-        let mut entity = *self.stack.last().unwrap();
-        for index in path {
-            entity = self.world.entity(entity).get::<Children>().unwrap()[*index as usize];
-        }
-        self.world.entity_mut(entity).insert((
-            Text::from_section(value, TextStyle::default()),
-            TextLayoutInfo::default(),
-            TextFlags::default(),
-            ContentSize::default(),
-        ));
-        self.element_id_to_bevy_ui_entity.insert(id, entity);
-        self.bevy_ui_entity_to_element_id.insert(entity, id);
+        with_world(|world| {
+            let mut entity = *self.stack.last().unwrap();
+            for index in path {
+                entity = world.entity(entity).get::<Children>().unwrap()[*index as usize];
+            }
+            world.entity_mut(entity).insert((
+                Text::from_section(value, TextStyle::default()),
+                TextLayoutInfo::default(),
+                TextFlags::default(),
+                ContentSize::default(),
+            ));
+            self.element_id_to_bevy_ui_entity.insert(id, entity);
+            self.bevy_ui_entity_to_element_id.insert(entity, id);
+        });
     }
 
     fn load_template(&mut self, name: &'static str, index: usize, id: ElementId) {
-        let entity = self.templates[name].roots[index].spawn(self.world);
-        self.element_id_to_bevy_ui_entity.insert(id, entity);
-        self.bevy_ui_entity_to_element_id.insert(entity, id);
-        self.stack.push(entity);
+        with_world(|world| {
+            let entity = self.templates[name].roots[index].spawn(world);
+            self.element_id_to_bevy_ui_entity.insert(id, entity);
+            self.bevy_ui_entity_to_element_id.insert(entity, id);
+            self.stack.push(entity);
+        });
     }
 
     fn replace_node_with(&mut self, id: ElementId, m: usize) {
-        let existing = self.element_id_to_bevy_ui_entity[&id];
-        let existing_parent = self.world.entity(existing).get::<Parent>().unwrap().get();
-        let mut existing_parent = self.world.entity_mut(existing_parent);
-
-        let existing_index = existing_parent
-            .get::<Children>()
-            .unwrap()
-            .iter()
-            .position(|child| *child == existing)
-            .unwrap();
-        existing_parent
-            .insert_children(existing_index, &self.stack.split_off(self.stack.len() - m));
-
-        DespawnRecursive { entity: existing }.apply(self.world);
-        // TODO: We're not removing child entities from the element maps
-        if let Some(existing_element_id) = self.bevy_ui_entity_to_element_id.remove(&existing) {
-            self.element_id_to_bevy_ui_entity
-                .remove(&existing_element_id);
-        }
+        with_world(|world| {
+            let existing = self.element_id_to_bevy_ui_entity[&id];
+            let existing_parent = world.entity(existing).get::<Parent>().unwrap().get();
+            let mut existing_parent = world.entity_mut(existing_parent);
+
+            let existing_index = existing_parent
+                .get::<Children>()
+                .unwrap()
+                .iter()
+                .position(|child| *child == existing)
+                .unwrap();
+            existing_parent
+                .insert_children(existing_index, &self.stack.split_off(self.stack.len() - m));
+
+            DespawnRecursive { entity: existing }.apply(world);
+            // TODO: We're not removing child entities from the element maps
+            if let Some(existing_element_id) = self.bevy_ui_entity_to_element_id.remove(&existing) {
+                self.element_id_to_bevy_ui_entity
+                    .remove(&existing_element_id);
+            }
+        });
     }
 
     fn replace_placeholder_with_nodes(&mut self, path: &'static [u8], m: usize) {
-        let mut existing = self.stack[self.stack.len() - m - 1];
-        for index in path {
-            existing = self.world.entity(existing).get::<Children>().unwrap()[*index as usize];
-        }
-        let existing_parent = self.world.entity(existing).get::<Parent>().unwrap().get();
-        let mut existing_parent = self.world.entity_mut(existing_parent);
-
-        let existing_index = existing_parent
-            .get::<Children>()
-            .unwrap()
-            .iter()
-            .position(|child| *child == existing)
-            .unwrap();
-        existing_parent
-            .insert_children(existing_index, &self.stack.split_off(self.stack.len() - m));
-
-        DespawnRecursive { entity: existing }.apply(self.world);
-        // TODO: We're not removing child entities from the element maps
-        if let Some(existing_element_id) = self.bevy_ui_entity_to_element_id.remove(&existing) {
-            self.element_id_to_bevy_ui_entity
-                .remove(&existing_element_id);
-        }
+        with_world(|world| {
+            let mut existing = self.stack[self.stack.len() - m - 1];
+            for index in path {
+                existing = world.entity(existing).get::<Children>().unwrap()[*index as usize];
+            }
+            let existing_parent = world.entity(existing).get::<Parent>().unwrap().get();
+            let mut existing_parent = world.entity_mut(existing_parent);
+
+            let existing_index = existing_parent
+                .get::<Children>()
+                .unwrap()
+                .iter()
+                .position(|child| *child == existing)
+                .unwrap();
+            existing_parent
+                .insert_children(existing_index, &self.stack.split_off(self.stack.len() - m));
+
+            DespawnRecursive { entity: existing }.apply(world);
+            // TODO: We're not removing child entities from the element maps
+            if let Some(existing_element_id) = self.bevy_ui_entity_to_element_id.remove(&existing) {
+                self.element_id_to_bevy_ui_entity
+                    .remove(&existing_element_id);
+            }
+        });
     }
 
     fn insert_nodes_after(&mut self, id: ElementId, m: usize) {
-        let entity = self.element_id_to_bevy_ui_entity[&id];
-        let parent = self.world.entity(entity).get::<Parent>().unwrap().get();
-        let mut parent = self.world.entity_mut(parent);
-        let index = parent
-            .get::<Children>()
-            .unwrap()
-            .iter()
-            .position(|child| *child == entity)
-            .unwrap();
-        parent.insert_children(index + 1, &self.stack.split_off(self.stack.len() - m));
+        with_world(|world| {
+            let entity = self.element_id_to_bevy_ui_entity[&id];
+            let parent = world.entity(entity).get::<Parent>().unwrap().get();
+            let mut parent = world.entity_mut(parent);
+            let index = parent
+                .get::<Children>()
+                .unwrap()
+                .iter()
+                .position(|child| *child == entity)
+                .unwrap();
+            parent.insert_children(index + 1, &self.stack.split_off(self.stack.len() - m));
+        });
     }
 
     fn insert_nodes_before(&mut self, id: ElementId, m: usize) {
-        let existing = self.element_id_to_bevy_ui_entity[&id];
-        let parent = self.world.entity(existing).get::<Parent>().unwrap().get();
-        let mut parent = self.world.entity_mut(parent);
-        let index = parent
-            .get::<Children>()
-            .unwrap()
-            .iter()
-            .position(|child| *child == existing)
-            .unwrap();
-        parent.insert_children(index, &self.stack.split_off(self.stack.len() - m));
+        with_world(|world| {
+            let existing = self.element_id_to_bevy_ui_entity[&id];
+            let parent = world.entity(existing).get::<Parent>().unwrap().get();
+            let mut parent = world.entity_mut(parent);
+            let index = parent
+                .get::<Children>()
+                .unwrap()
+                .iter()
+                .position(|child| *child == existing)
+                .unwrap();
+            parent.insert_children(index, &self.stack.split_off(self.stack.len() - m));
+        });
     }
 
     fn set_attribute(
@@ -201,86 +387,65 @@ impl<'a> WriteMutations for MutationApplier<'a> {
         value: &AttributeValue,
         id: ElementId,
     ) {
-        let value = match value {
-            AttributeValue::Text(value) => value,
-            AttributeValue::None => todo!("Remove the attribute"),
-            value => {
-                panic!("Encountered unsupported bevy_dioxus attribute `{name}: {value:?}`.")
+        with_world(|world| {
+            let entity = self.element_id_to_bevy_ui_entity[&id];
+            if let Err(reason) = self.try_set_attribute(world, name, value, entity) {
+                UiDiagnostic {
+                    element_path: self.element_path(world, entity),
+                    attribute: Some(name.to_owned()),
+                    value: Some(match value {
+                        AttributeValue::Text(value) => value.clone(),
+                        value => format!("{value:?}"),
+                    }),
+                    reason,
+                }
+                .report(world);
             }
-        };
-
-        let (
-            mut style,
-            mut border_color,
-            mut outline,
-            mut background_color,
-            mut transform,
-            mut visibility,
-            mut z_index,
-            mut text,
-            mut image,
-        ) = self
-            .world
-            .query::<(
-                &mut Style,
-                &mut BorderColor,
-                &mut Outline,
-                &mut BackgroundColor,
-                &mut Transform,
-                &mut Visibility,
-                &mut ZIndex,
-                Option<&mut Text>,
-                Option<&mut UiImage>,
-            )>()
-            .get_mut(self.world, self.element_id_to_bevy_ui_entity[&id])
-            .unwrap();
-
-        set_attribute(
-            name,
-            &value,
-            &mut style,
-            &mut border_color,
-            &mut outline,
-            &mut background_color,
-            &mut transform,
-            &mut visibility,
-            &mut z_index,
-            text.as_deref_mut(),
-            image.as_deref_mut(),
-            self.asset_server,
-        );
+        });
     }
 
     fn set_node_text(&mut self, value: &str, id: ElementId) {
-        self.world
-            .entity_mut(self.element_id_to_bevy_ui_entity[&id])
-            .insert(Text::from_section(value, TextStyle::default()));
+        with_world(|world| {
+            world
+                .entity_mut(self.element_id_to_bevy_ui_entity[&id])
+                .insert(Text::from_section(value, TextStyle::default()));
+        });
     }
 
     fn create_event_listener(&mut self, name: &'static str, id: ElementId) {
-        insert_event_listener(
-            &name,
-            self.world
-                .entity_mut(self.element_id_to_bevy_ui_entity[&id]),
-        );
+        with_world(|world| {
+            let entity = self.element_id_to_bevy_ui_entity[&id];
+            if let Err(reason) = insert_event_listener(name, world.entity_mut(entity)) {
+                UiDiagnostic {
+                    element_path: self.element_path(world, entity),
+                    attribute: Some(format!("on{name}")),
+                    value: None,
+                    reason,
+                }
+                .report(world);
+            }
+        });
     }
 
     fn remove_event_listener(&mut self, name: &'static str, id: ElementId) {
-        remove_event_listener(
-            &name,
-            self.world
-                .entity_mut(self.element_id_to_bevy_ui_entity[&id]),
-        );
+        with_world(|world| {
+            remove_event_listener(
+                &name,
+                world.entity_mut(self.element_id_to_bevy_ui_entity[&id]),
+            );
+        });
     }
 
     fn remove_node(&mut self, id: ElementId) {
-        let entity = self.element_id_to_bevy_ui_entity[&id];
-        DespawnRecursive { entity }.apply(self.world);
-        // TODO: We're not removing child entities from the element maps
-        if let Some(existing_element_id) = self.bevy_ui_entity_to_element_id.remove(&entity) {
-            self.element_id_to_bevy_ui_entity
-                .remove(&existing_element_id);
-        }
+        with_world(|world| {
+            let entity = self.element_id_to_bevy_ui_entity[&id];
+            DespawnRecursive { entity }.apply(world);
+            // TODO: We're not removing child entities from the element maps
+            if let Some(existing_element_id) = self.bevy_ui_entity_to_element_id.remove(&entity) {
+                self.element_id_to_bevy_ui_entity
+                    .remove(&existing_element_id);
+            }
+        });
     }
 
     fn push_root(&mut self, id: ElementId) {
@@ -303,27 +468,48 @@ enum BevyTemplateNode {
         children: Box<[Self]>,
     },
     ImageNode {
-        image: UiImage,
+        image: ImageComponents,
+        style: StyleComponents,
+        children: Box<[Self]>,
+    },
+    MaterialNode {
+        name: &'static str,
+        material: UiMaterialType,
+        style: StyleComponents,
+        children: Box<[Self]>,
+    },
+    CustomNode {
+        tag: &'static str,
+        path: String,
+        attributes: Box<[(&'static str, &'static str)]>,
         style: StyleComponents,
         children: Box<[Self]>,
     },
     IntrinsicTextNode(Text),
 }
 
+struct TemplateContext<'a> {
+    asset_server: &'a AssetServer,
+    custom_elements: &'a CustomElements,
+    custom_attributes: &'a CustomAttributes,
+    ui_materials: &'a UiMaterials,
+    diagnostics: Vec<UiDiagnostic>,
+}
+
 impl BevyTemplate {
-    fn from_dioxus(template: &Template, asset_server: &AssetServer) -> Self {
+    fn from_dioxus(template: &Template, context: &mut TemplateContext) -> Self {
         Self {
             roots: template
                 .roots
                 .iter()
-                .map(|node| BevyTemplateNode::from_dioxus(node, asset_server))
+                .map(|node| BevyTemplateNode::from_dioxus(node, "", context))
                 .collect(),
         }
     }
 }
 
 impl BevyTemplateNode {
-    fn from_dioxus(node: &TemplateNode, asset_server: &AssetServer) -> Self {
+    fn from_dioxus(node: &TemplateNode, parent_path: &str, context: &mut TemplateContext) -> Self {
         match node {
             TemplateNode::Element {
                 tag: "node",
@@ -331,12 +517,19 @@ impl BevyTemplateNode {
                 attrs,
                 children,
             } => {
-                let (style, _, _) = parse_template_attributes(attrs, Color::NONE, asset_server);
+                let path = child_path(parent_path, "node");
+                let (style, _, _) = parse_template_attributes(
+                    attrs.iter(),
+                    ElementKind::Node,
+                    None,
+                    &path,
+                    context,
+                );
                 Self::Node {
                     style,
                     children: children
                         .iter()
-                        .map(|node| Self::from_dioxus(node, asset_server))
+                        .map(|node| Self::from_dioxus(node, &path, context))
                         .collect(),
                 }
             }
@@ -346,13 +539,20 @@ impl BevyTemplateNode {
                 attrs,
                 children,
             } => {
-                let (style, text, _) = parse_template_attributes(attrs, Color::NONE, asset_server);
+                let path = child_path(parent_path, "text");
+                let (style, text, _) = parse_template_attributes(
+                    attrs.iter(),
+                    ElementKind::Text,
+                    None,
+                    &path,
+                    context,
+                );
                 Self::TextNode {
                     text,
                     style,
                     children: children
                         .iter()
-                        .map(|node| Self::from_dioxus(node, asset_server))
+                        .map(|node| Self::from_dioxus(node, &path, context))
                         .collect(),
                 }
             }
@@ -362,14 +562,115 @@ impl BevyTemplateNode {
                 attrs,
                 children,
             } => {
-                let (style, _, image) =
-                    parse_template_attributes(attrs, Color::WHITE, asset_server);
+                let path = child_path(parent_path, "image");
+                let (style, _, image) = parse_template_attributes(
+                    attrs.iter(),
+                    ElementKind::Image,
+                    None,
+                    &path,
+                    context,
+                );
                 Self::ImageNode {
                     image,
                     style,
                     children: children
                         .iter()
-                        .map(|node| Self::from_dioxus(node, asset_server))
+                        .map(|node| Self::from_dioxus(node, &path, context))
+                        .collect(),
+                }
+            }
+            TemplateNode::Element {
+                tag: "material_node",
+                namespace: Some("bevy_ui"),
+                attrs,
+                children,
+            } => {
+                let path = child_path(parent_path, "material_node");
+                let name = attrs.iter().find_map(|attribute| match attribute {
+                    TemplateAttribute::Static {
+                        name: "material",
+                        value,
+                        ..
+                    } => Some(*value),
+                    _ => None,
+                });
+                let material = name.and_then(|name| context.ui_materials.get(name).copied());
+                let (style, _, _) = parse_template_attributes(
+                    attrs.iter().filter(|attribute| {
+                        !matches!(
+                            attribute,
+                            TemplateAttribute::Static {
+                                name: "material",
+                                ..
+                            }
+                        )
+                    }),
+                    match material {
+                        Some(_) => ElementKind::MaterialNode,
+                        None => ElementKind::Node,
+                    },
+                    material.map(|material| material.handle_type_id),
+                    &path,
+                    context,
+                );
+                let children = children
+                    .iter()
+                    .map(|node| Self::from_dioxus(node, &path, context))
+                    .collect();
+                match (name, material) {
+                    (Some(name), Some(material)) => Self::MaterialNode {
+                        name,
+                        material,
+                        style,
+                        children,
+                    },
+                    _ => {
+                        context.diagnostics.push(UiDiagnostic {
+                            element_path: path,
+                            attribute: Some("material".to_owned()),
+                            value: name.map(str::to_owned),
+                            reason: "unregistered material, spawning a `node` instead".to_owned(),
+                        });
+                        Self::Node { style, children }
+                    }
+                }
+            }
+            TemplateNode::Element {
+                tag,
+                namespace: _,
+                attrs,
+                children,
+            } if context.custom_elements.contains_key(tag) => {
+                let path = child_path(parent_path, tag);
+                let custom_attributes = context.custom_elements[tag].attributes;
+                let (style, _, _) = parse_template_attributes(
+                    attrs.iter().filter(|attribute| match attribute {
+                        TemplateAttribute::Static { name, .. } => !custom_attributes.contains(name),
+                        TemplateAttribute::Dynamic { .. } => true,
+                    }),
+                    ElementKind::Node,
+                    None,
+                    &path,
+                    context,
+                );
+                Self::CustomNode {
+                    tag: *tag,
+                    path: path.clone(),
+                    attributes: attrs
+                        .iter()
+                        .filter_map(|attribute| match attribute {
+                            TemplateAttribute::Static { name, value, .. }
+                                if custom_attributes.contains(name) =>
+                            {
+                                Some((*name, *value))
+                            }
+                            _ => None,
+                        })
+                        .collect(),
+                    style,
+                    children: children
+                        .iter()
+                        .map(|node| Self::from_dioxus(node, &path, context))
                         .collect(),
                 }
             }
@@ -385,17 +686,28 @@ impl BevyTemplateNode {
             }
             TemplateNode::Element {
                 tag,
-                namespace: None,
-                ..
-            } => {
-                panic!("Encountered unsupported bevy_dioxus tag `{tag}`.")
-            }
-            TemplateNode::Element {
-                tag,
-                namespace: Some(namespace),
-                ..
+                namespace,
+                attrs: _,
+                children,
             } => {
-                panic!("Encountered unsupported bevy_dioxus tag `{namespace}::{tag}`.")
+                let tag = match namespace {
+                    Some(namespace) => format!("{namespace}::{tag}"),
+                    None => tag.to_string(),
+                };
+                let path = child_path(parent_path, &tag);
+                context.diagnostics.push(UiDiagnostic {
+                    element_path: path.clone(),
+                    attribute: None,
+                    value: None,
+                    reason: "unsupported element, spawning a `node` instead".to_owned(),
+                });
+                Self::Node {
+                    style: StyleComponents::default(),
+                    children: children
+                        .iter()
+                        .map(|node| Self::from_dioxus(node, &path, context))
+                        .collect(),
+                }
             }
         }
     }
@@ -407,21 +719,24 @@ impl BevyTemplateNode {
                     .iter()
                     .map(|child| child.spawn(world))
                     .collect::<Box<[_]>>();
-                world
-                    .spawn((
-                        NodeBundle {
-                            style: style.style.clone(),
-                            border_color: style.border_color,
-                            background_color: style.background_color,
-                            transform: style.transform,
-                            visibility: style.visibility,
-                            z_index: style.z_index,
-                            ..default()
-                        },
-                        style.outline,
-                    ))
-                    .push_children(&children)
-                    .id()
+                let mut entity = world.spawn((
+                    NodeBundle {
+                        style: style.style.clone(),
+                        border_color: style.border_color,
+                        background_color: style.background_color,
+                        visibility: style.visibility,
+                        z_index: style.z_index,
+                        ..default()
+                    },
+                    style.outline,
+                    style.border_radius,
+                    style.box_shadow,
+                    style.rounded_box,
+                    style.transform,
+                ));
+                update_background_color(&mut entity);
+                style.apply_custom_attributes(&mut entity);
+                entity.push_children(&children).id()
             }
             BevyTemplateNode::TextNode {
                 text,
@@ -432,25 +747,28 @@ impl BevyTemplateNode {
                     .iter()
                     .map(|child| child.spawn(world))
                     .collect::<Box<[_]>>();
-                world
-                    .spawn(NodeBundle {
-                        border_color: style.border_color,
+                let mut entity = world.spawn(NodeBundle {
+                    border_color: style.border_color,
+                    ..default()
+                });
+                entity.insert((
+                    TextBundle {
+                        text: text.clone(),
+                        style: style.style.clone(),
+                        background_color: style.background_color,
+                        visibility: style.visibility,
+                        z_index: style.z_index,
                         ..default()
-                    })
-                    .insert((
-                        TextBundle {
-                            text: text.clone(),
-                            style: style.style.clone(),
-                            background_color: style.background_color,
-                            transform: style.transform,
-                            visibility: style.visibility,
-                            z_index: style.z_index,
-                            ..default()
-                        },
-                        style.outline,
-                    ))
-                    .push_children(&children)
-                    .id()
+                    },
+                    style.outline,
+                    style.border_radius,
+                    style.box_shadow,
+                    style.rounded_box,
+                    style.transform,
+                ));
+                update_background_color(&mut entity);
+                style.apply_custom_attributes(&mut entity);
+                entity.push_children(&children).id()
             }
             BevyTemplateNode::ImageNode {
                 image,
@@ -461,25 +779,113 @@ impl BevyTemplateNode {
                     .iter()
                     .map(|child| child.spawn(world))
                     .collect::<Box<[_]>>();
-                world
-                    .spawn(NodeBundle {
-                        border_color: style.border_color,
-                        ..default()
-                    })
+                let mut entity = world.spawn(NodeBundle {
+                    border_color: style.border_color,
+                    ..default()
+                });
+                entity
                     .insert((
                         ImageBundle {
-                            image: image.clone(),
                             style: style.style.clone(),
                             background_color: style.background_color,
-                            transform: style.transform,
                             visibility: style.visibility,
                             z_index: style.z_index,
                             ..default()
                         },
                         style.outline,
+                        style.border_radius,
+                        style.box_shadow,
+                        style.rounded_box,
+                        style.transform,
                     ))
-                    .push_children(&children)
-                    .id()
+                    .push_children(&children);
+                image.insert(&mut entity);
+                style.apply_custom_attributes(&mut entity);
+                entity.id()
+            }
+            BevyTemplateNode::MaterialNode {
+                name,
+                material,
+                style,
+                children,
+            } => {
+                let children = children
+                    .iter()
+                    .map(|child| child.spawn(world))
+                    .collect::<Box<[_]>>();
+                let mut entity = world.spawn((
+                    NodeBundle {
+                        style: style.style.clone(),
+                        border_color: style.border_color,
+                        background_color: style.background_color,
+                        visibility: style.visibility,
+                        z_index: style.z_index,
+                        ..default()
+                    },
+                    style.outline,
+                    style.border_radius,
+                    style.box_shadow,
+                    style.rounded_box,
+                    style.transform,
+                    UiMaterialTag(*name),
+                ));
+                // Like `MaterialNodeBundle`, since Bevy only draws materials of nodes without one
+                entity.remove::<BackgroundColor>();
+                (material.spawn)(&mut entity);
+                style.apply_custom_attributes(&mut entity);
+                entity.push_children(&children).id()
+            }
+            BevyTemplateNode::CustomNode {
+                tag,
+                path,
+                attributes,
+                style,
+                children,
+            } => {
+                let children = children
+                    .iter()
+                    .map(|child| child.spawn(world))
+                    .collect::<Box<[_]>>();
+                let custom_element = world.resource::<CustomElements>()[tag];
+                let mut entity = world.spawn((
+                    NodeBundle {
+                        style: style.style.clone(),
+                        border_color: style.border_color,
+                        background_color: style.background_color,
+                        visibility: style.visibility,
+                        z_index: style.z_index,
+                        ..default()
+                    },
+                    style.outline,
+                    style.border_radius,
+                    style.box_shadow,
+                    style.rounded_box,
+                    style.transform,
+                    CustomElementTag(*tag),
+                ));
+                update_background_color(&mut entity);
+                (custom_element.spawn)(&mut entity);
+                let mut diagnostics = Vec::new();
+                for (name, value) in attributes.iter() {
+                    if let Err(reason) = (custom_element.set_attribute)(
+                        name,
+                        &AttributeValue::Text(value.to_string()),
+                        &mut entity,
+                    ) {
+                        diagnostics.push(UiDiagnostic {
+                            element_path: path.clone(),
+                            attribute: Some(name.to_string()),
+                            value: Some(value.to_string()),
+                            reason,
+                        });
+                    }
+                }
+                style.apply_custom_attributes(&mut entity);
+                let entity = entity.push_children(&children).id();
+                for diagnostic in diagnostics {
+                    diagnostic.report(world);
+                }
+                entity
             }
             Self::IntrinsicTextNode(text) => world
                 .spawn(TextBundle {
@@ -491,17 +897,33 @@ impl BevyTemplateNode {
     }
 }
 
-fn parse_template_attributes(
-    attributes: &[TemplateAttribute],
-    background_color: Color,
-    asset_server: &AssetServer,
-) -> (StyleComponents, Text, UiImage) {
+/// The kind of element a template node is spawned as, which decides the components its
+/// attributes can target.
+#[derive(Clone, Copy, PartialEq)]
+enum ElementKind {
+    Node,
+    Text,
+    Image,
+    MaterialNode,
+}
+
+fn parse_template_attributes<'a>(
+    attributes: impl Iterator<Item = &'a TemplateAttribute>,
+    kind: ElementKind,
+    material: Option<TypeId>,
+    element_path: &str,
+    context: &mut TemplateContext,
+) -> (StyleComponents, Text, ImageComponents) {
     let mut style = StyleComponents {
-        background_color: BackgroundColor(background_color),
+        background_color: match kind {
+            ElementKind::Image => BackgroundColor(Color::WHITE),
+            _ => BackgroundColor(Color::NONE),
+        },
         ..default()
     };
+    let supports_rounded_box = matches!(kind, ElementKind::Node | ElementKind::Text);
     let mut text = Text::from_section("", TextStyle::default());
-    let mut image = UiImage::default();
+    let mut image = ImageComponents::default();
     for attribute in attributes {
         if let TemplateAttribute::Static {
             name,
@@ -509,20 +931,41 @@ fn parse_template_attributes(
             namespace: _,
         } = attribute
         {
-            set_attribute(
-                name,
-                value,
-                &mut style.style,
-                &mut style.border_color,
-                &mut style.outline,
-                &mut style.background_color,
-                &mut style.transform,
-                &mut style.visibility,
-                &mut style.z_index,
-                Some(&mut text),
-                Some(&mut image),
-                asset_server,
-            );
+            let result = match context.custom_attributes.get(name, material) {
+                Some(custom_attribute) => custom_attribute
+                    .and_then(|custom_attribute| {
+                        custom_attribute.parse(AttributeInput::Text(value))
+                    })
+                    .map(|apply| style.custom_attributes.push(apply)),
+                None => set_attribute(
+                    name,
+                    AttributeInput::Text(value),
+                    AttributeTargets {
+                        style: &mut style.style,
+                        border_color: &mut style.border_color,
+                        outline: &mut style.outline,
+                        border_radius: &mut style.border_radius,
+                        box_shadow: &mut style.box_shadow,
+                        rounded_box: supports_rounded_box.then_some(&mut style.rounded_box),
+                        background_color: (kind != ElementKind::MaterialNode)
+                            .then_some(&mut style.background_color),
+                        transform: &mut style.transform,
+                        visibility: &mut style.visibility,
+                        z_index: &mut style.z_index,
+                        text: (kind == ElementKind::Text).then_some(&mut text),
+                        image: (kind == ElementKind::Image).then_some(&mut image),
+                    },
+                    context.asset_server,
+                ),
+            };
+            if let Err(reason) = result {
+                context.diagnostics.push(UiDiagnostic {
+                    element_path: element_path.to_owned(),
+                    attribute: Some(name.to_string()),
+                    value: Some(value.to_string()),
+                    reason,
+                });
+            }
         }
     }
     (style, text, image)
@@ -533,8 +976,62 @@ struct StyleComponents {
     style: Style,
     border_color: BorderColor,
     outline: Outline,
+    border_radius: BorderRadius,
+    box_shadow: BoxShadow,
+    rounded_box: RoundedBox,
     background_color: BackgroundColor,
-    transform: Transform,
+    transform: UiTransform,
     visibility: Visibility,
     z_index: ZIndex,
+    custom_attributes: Vec<ApplyAttribute>,
+}
+
+impl StyleComponents {
+    fn apply_custom_attributes(&self, entity: &mut EntityWorldMut) {
+        for apply in &self.custom_attributes {
+            apply(entity);
+        }
+    }
+}
+
+#[derive(Default, Clone)]
+pub struct ImageComponents {
+    pub image: UiImage,
+    pub scale_mode: Option<ImageScaleMode>,
+    pub texture_atlas: Option<TextureAtlas>,
+    /// The `image_texture_atlas_index` of an image without a layout yet.
+    pub pending_texture_atlas_index: Option<usize>,
+}
+
+#[derive(Component, Clone, Copy)]
+struct PendingTextureAtlasIndex(usize);
+
+impl ImageComponents {
+    fn from_entity(world: &World, entity: Entity) -> Option<Self> {
+        let entity = world.entity(entity);
+        Some(Self {
+            image: entity.get::<UiImage>()?.clone(),
+            scale_mode: entity.get::<ImageScaleMode>().cloned(),
+            texture_atlas: entity.get::<TextureAtlas>().cloned(),
+            pending_texture_atlas_index: entity
+                .get::<PendingTextureAtlasIndex>()
+                .map(|pending| pending.0),
+        })
+    }
+
+    fn insert(&self, entity: &mut EntityWorldMut) {
+        entity.insert(self.image.clone());
+        match &self.scale_mode {
+            Some(scale_mode) => entity.insert(scale_mode.clone()),
+            None => entity.remove::<ImageScaleMode>(),
+        };
+        match &self.texture_atlas {
+            Some(texture_atlas) => entity.insert(texture_atlas.clone()),
+            None => entity.remove::<TextureAtlas>(),
+        };
+        match self.pending_texture_atlas_index {
+            Some(index) => entity.insert(PendingTextureAtlasIndex(index)),
+            None => entity.remove::<PendingTextureAtlasIndex>(),
+        };
+    }
 }
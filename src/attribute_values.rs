@@ -0,0 +1,177 @@
+use bevy::{
+    asset::{Asset, Handle},
+    ecs::{
+        bundle::Bundle,
+        component::{Component, ComponentId},
+        world::EntityWorldMut,
+    },
+};
+use dioxus::dioxus_core::{AttributeValue, IntoAttributeValue};
+use std::{
+    any::{Any, TypeId},
+    rc::Rc,
+};
+
+/// Wraps a [`Handle`] so that it can be passed to an element attribute.
+///
+/// ```ignore
+/// image { image_handle: AssetHandle(render_target.clone()) }
+/// ```
+pub struct AssetHandle<A: Asset>(pub Handle<A>);
+
+impl<A: Asset> Clone for AssetHandle<A> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<A: Asset> PartialEq for AssetHandle<A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<A: Asset> From<Handle<A>> for AssetHandle<A> {
+    fn from(handle: Handle<A>) -> Self {
+        Self(handle)
+    }
+}
+
+impl<A: Asset> IntoAttributeValue for AssetHandle<A> {
+    fn into_value(self) -> AttributeValue {
+        AttributeValue::any_value(self)
+    }
+}
+
+/// A bundle of components to insert onto the element's entity, via the `components` attribute.
+///
+/// The bundle is only inserted again when it changes. If it changes to a bundle of another type,
+/// the components the previous bundle added are removed first, while components the element
+/// already had, like its `Style`, keep the values the bundle gave them.
+///
+/// ```ignore
+/// node { components: Components::new((MyMarker, Name::new("Inventory"))) }
+/// ```
+#[derive(Clone)]
+pub struct Components {
+    bundle: Rc<dyn Any>,
+    insert: fn(&dyn Any, &mut EntityWorldMut),
+    eq: fn(&dyn Any, &dyn Any) -> bool,
+}
+
+impl Components {
+    pub fn new<B: Bundle + Clone + PartialEq>(bundle: B) -> Self {
+        Self {
+            bundle: Rc::new(bundle),
+            insert: |bundle, entity| {
+                entity.insert(bundle.downcast_ref::<B>().unwrap().clone());
+            },
+            eq: |bundle, other| bundle.downcast_ref::<B>() == other.downcast_ref::<B>(),
+        }
+    }
+}
+
+impl PartialEq for Components {
+    fn eq(&self, other: &Self) -> bool {
+        (self.eq)(&*self.bundle, &*other.bundle)
+    }
+}
+
+impl IntoAttributeValue for Components {
+    fn into_value(self) -> AttributeValue {
+        AttributeValue::any_value(self)
+    }
+}
+
+#[derive(Component)]
+pub struct InsertedComponents {
+    bundle_type: TypeId,
+    /// The components the element didn't have before, which are removed along with the bundle.
+    added: Vec<ComponentId>,
+}
+
+pub fn set_components_attribute(
+    value: &AttributeValue,
+    entity: &mut EntityWorldMut,
+) -> Result<(), String> {
+    let components = match value {
+        AttributeValue::Any(value) => match value.as_any().downcast_ref::<Components>() {
+            Some(components) => Some(components),
+            None => return Err("expected `Components`".to_owned()),
+        },
+        AttributeValue::None => None,
+        _ => return Err("expected `Components`".to_owned()),
+    };
+    let bundle_type = components.map(|components| (*components.bundle).type_id());
+
+    // Inserting a bundle of the same type overwrites it, and the element's own components are never
+    // removed, since every element needs them
+    let mut added = Vec::new();
+    if let Some(inserted) = entity.take::<InsertedComponents>() {
+        if Some(inserted.bundle_type) == bundle_type {
+            added = inserted.added;
+        } else {
+            for component_id in inserted.added {
+                entity.remove_by_id(component_id);
+            }
+        }
+    }
+    if let Some(components) = components {
+        let existing = entity.archetype().components().collect::<Vec<_>>();
+        (components.insert)(&*components.bundle, entity);
+        added.extend(
+            entity
+                .archetype()
+                .components()
+                .filter(|component_id| !existing.contains(component_id)),
+        );
+        entity.insert(InsertedComponents {
+            bundle_type: (*components.bundle).type_id(),
+            added,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::{
+        ecs::world::World,
+        render::color::Color,
+        ui::{node_bundles::NodeBundle, BackgroundColor, Style},
+    };
+
+    #[derive(Component, Clone, PartialEq)]
+    struct Highlighted;
+
+    #[derive(Component, Clone, PartialEq)]
+    struct Disabled;
+
+    fn set(entity: &mut EntityWorldMut, components: Option<Components>) {
+        let value = components.map_or(AttributeValue::None, AttributeValue::any_value);
+        assert_eq!(set_components_attribute(&value, entity), Ok(()));
+    }
+
+    #[test]
+    fn keeps_element_components_when_bundle_changes_type() {
+        let mut world = World::new();
+        let mut entity = world.spawn(NodeBundle::default());
+
+        let highlighted = (Highlighted, BackgroundColor(Color::RED));
+        set(&mut entity, Some(Components::new(highlighted)));
+        assert!(entity.contains::<Highlighted>());
+        assert_eq!(entity.get::<BackgroundColor>().unwrap().0, Color::RED);
+
+        set(&mut entity, Some(Components::new(Disabled)));
+        assert!(!entity.contains::<Highlighted>());
+        assert!(entity.contains::<Disabled>());
+        assert!(entity.contains::<BackgroundColor>());
+
+        set(&mut entity, None);
+        assert!(!entity.contains::<Disabled>());
+        assert!(!entity.contains::<InsertedComponents>());
+        assert!(entity.contains::<Style>());
+        assert!(entity.contains::<BackgroundColor>());
+    }
+}
@@ -267,3 +267,411 @@ pub const ROSE_700: &str = "#be123c";
 pub const ROSE_800: &str = "#9f1239";
 pub const ROSE_900: &str = "#881337";
 pub const ROSE_950: &str = "#4c0519";
+
+/// Looks up a Tailwind color by name, e.g. `"violet-500"`.
+pub fn tailwind(name: &str) -> Option<&'static str> {
+    Some(match name {
+        "black" => BLACK,
+        "white" => WHITE,
+        "transparent" => TRANSPARENT,
+        "slate-50" => SLATE_50,
+        "slate-100" => SLATE_100,
+        "slate-200" => SLATE_200,
+        "slate-300" => SLATE_300,
+        "slate-400" => SLATE_400,
+        "slate-500" => SLATE_500,
+        "slate-600" => SLATE_600,
+        "slate-700" => SLATE_700,
+        "slate-800" => SLATE_800,
+        "slate-900" => SLATE_900,
+        "slate-950" => SLATE_950,
+        "gray-50" => GRAY_50,
+        "gray-100" => GRAY_100,
+        "gray-200" => GRAY_200,
+        "gray-300" => GRAY_300,
+        "gray-400" => GRAY_400,
+        "gray-500" => GRAY_500,
+        "gray-600" => GRAY_600,
+        "gray-700" => GRAY_700,
+        "gray-800" => GRAY_800,
+        "gray-900" => GRAY_900,
+        "gray-950" => GRAY_950,
+        "zinc-50" => ZINC_50,
+        "zinc-100" => ZINC_100,
+        "zinc-200" => ZINC_200,
+        "zinc-300" => ZINC_300,
+        "zinc-400" => ZINC_400,
+        "zinc-500" => ZINC_500,
+        "zinc-600" => ZINC_600,
+        "zinc-700" => ZINC_700,
+        "zinc-800" => ZINC_800,
+        "zinc-900" => ZINC_900,
+        "zinc-950" => ZINC_950,
+        "neutral-50" => NEUTRAL_50,
+        "neutral-100" => NEUTRAL_100,
+        "neutral-200" => NEUTRAL_200,
+        "neutral-300" => NEUTRAL_300,
+        "neutral-400" => NEUTRAL_400,
+        "neutral-500" => NEUTRAL_500,
+        "neutral-600" => NEUTRAL_600,
+        "neutral-700" => NEUTRAL_700,
+        "neutral-800" => NEUTRAL_800,
+        "neutral-900" => NEUTRAL_900,
+        "neutral-950" => NEUTRAL_950,
+        "stone-50" => STONE_50,
+        "stone-100" => STONE_100,
+        "stone-200" => STONE_200,
+        "stone-300" => STONE_300,
+        "stone-400" => STONE_400,
+        "stone-500" => STONE_500,
+        "stone-600" => STONE_600,
+        "stone-700" => STONE_700,
+        "stone-800" => STONE_800,
+        "stone-900" => STONE_900,
+        "stone-950" => STONE_950,
+        "red-50" => RED_50,
+        "red-100" => RED_100,
+        "red-200" => RED_200,
+        "red-300" => RED_300,
+        "red-400" => RED_400,
+        "red-500" => RED_500,
+        "red-600" => RED_600,
+        "red-700" => RED_700,
+        "red-800" => RED_800,
+        "red-900" => RED_900,
+        "red-950" => RED_950,
+        "orange-50" => ORANGE_50,
+        "orange-100" => ORANGE_100,
+        "orange-200" => ORANGE_200,
+        "orange-300" => ORANGE_300,
+        "orange-400" => ORANGE_400,
+        "orange-500" => ORANGE_500,
+        "orange-600" => ORANGE_600,
+        "orange-700" => ORANGE_700,
+        "orange-800" => ORANGE_800,
+        "orange-900" => ORANGE_900,
+        "orange-950" => ORANGE_950,
+        "amber-50" => AMBER_50,
+        "amber-100" => AMBER_100,
+        "amber-200" => AMBER_200,
+        "amber-300" => AMBER_300,
+        "amber-400" => AMBER_400,
+        "amber-500" => AMBER_500,
+        "amber-600" => AMBER_600,
+        "amber-700" => AMBER_700,
+        "amber-800" => AMBER_800,
+        "amber-900" => AMBER_900,
+        "amber-950" => AMBER_950,
+        "yellow-50" => YELLOW_50,
+        "yellow-100" => YELLOW_100,
+        "yellow-200" => YELLOW_200,
+        "yellow-300" => YELLOW_300,
+        "yellow-400" => YELLOW_400,
+        "yellow-500" => YELLOW_500,
+        "yellow-600" => YELLOW_600,
+        "yellow-700" => YELLOW_700,
+        "yellow-800" => YELLOW_800,
+        "yellow-900" => YELLOW_900,
+        "yellow-950" => YELLOW_950,
+        "lime-50" => LIME_50,
+        "lime-100" => LIME_100,
+        "lime-200" => LIME_200,
+        "lime-300" => LIME_300,
+        "lime-400" => LIME_400,
+        "lime-500" => LIME_500,
+        "lime-600" => LIME_600,
+        "lime-700" => LIME_700,
+        "lime-800" => LIME_800,
+        "lime-900" => LIME_900,
+        "lime-950" => LIME_950,
+        "green-50" => GREEN_50,
+        "green-100" => GREEN_100,
+        "green-200" => GREEN_200,
+        "green-300" => GREEN_300,
+        "green-400" => GREEN_400,
+        "green-500" => GREEN_500,
+        "green-600" => GREEN_600,
+        "green-700" => GREEN_700,
+        "green-800" => GREEN_800,
+        "green-900" => GREEN_900,
+        "green-950" => GREEN_950,
+        "emerald-50" => EMERALD_50,
+        "emerald-100" => EMERALD_100,
+        "emerald-200" => EMERALD_200,
+        "emerald-300" => EMERALD_300,
+        "emerald-400" => EMERALD_400,
+        "emerald-500" => EMERALD_500,
+        "emerald-600" => EMERALD_600,
+        "emerald-700" => EMERALD_700,
+        "emerald-800" => EMERALD_800,
+        "emerald-900" => EMERALD_900,
+        "emerald-950" => EMERALD_950,
+        "teal-50" => TEAL_50,
+        "teal-100" => TEAL_100,
+        "teal-200" => TEAL_200,
+        "teal-300" => TEAL_300,
+        "teal-400" => TEAL_400,
+        "teal-500" => TEAL_500,
+        "teal-600" => TEAL_600,
+        "teal-700" => TEAL_700,
+        "teal-800" => TEAL_800,
+        "teal-900" => TEAL_900,
+        "teal-950" => TEAL_950,
+        "cyan-50" => CYAN_50,
+        "cyan-100" => CYAN_100,
+        "cyan-200" => CYAN_200,
+        "cyan-300" => CYAN_300,
+        "cyan-400" => CYAN_400,
+        "cyan-500" => CYAN_500,
+        "cyan-600" => CYAN_600,
+        "cyan-700" => CYAN_700,
+        "cyan-800" => CYAN_800,
+        "cyan-900" => CYAN_900,
+        "cyan-950" => CYAN_950,
+        "sky-50" => SKY_50,
+        "sky-100" => SKY_100,
+        "sky-200" => SKY_200,
+        "sky-300" => SKY_300,
+        "sky-400" => SKY_400,
+        "sky-500" => SKY_500,
+        "sky-600" => SKY_600,
+        "sky-700" => SKY_700,
+        "sky-800" => SKY_800,
+        "sky-900" => SKY_900,
+        "sky-950" => SKY_950,
+        "blue-50" => BLUE_50,
+        "blue-100" => BLUE_100,
+        "blue-200" => BLUE_200,
+        "blue-300" => BLUE_300,
+        "blue-400" => BLUE_400,
+        "blue-500" => BLUE_500,
+        "blue-600" => BLUE_600,
+        "blue-700" => BLUE_700,
+        "blue-800" => BLUE_800,
+        "blue-900" => BLUE_900,
+        "blue-950" => BLUE_950,
+        "indigo-50" => INDIGO_50,
+        "indigo-100" => INDIGO_100,
+        "indigo-200" => INDIGO_200,
+        "indigo-300" => INDIGO_300,
+        "indigo-400" => INDIGO_400,
+        "indigo-500" => INDIGO_500,
+        "indigo-600" => INDIGO_600,
+        "indigo-700" => INDIGO_700,
+        "indigo-800" => INDIGO_800,
+        "indigo-900" => INDIGO_900,
+        "indigo-950" => INDIGO_950,
+        "violet-50" => VIOLET_50,
+        "violet-100" => VIOLET_100,
+        "violet-200" => VIOLET_200,
+        "violet-300" => VIOLET_300,
+        "violet-400" => VIOLET_400,
+        "violet-500" => VIOLET_500,
+        "violet-600" => VIOLET_600,
+        "violet-700" => VIOLET_700,
+        "violet-800" => VIOLET_800,
+        "violet-900" => VIOLET_900,
+        "violet-950" => VIOLET_950,
+        "purple-50" => PURPLE_50,
+        "purple-100" => PURPLE_100,
+        "purple-200" => PURPLE_200,
+        "purple-300" => PURPLE_300,
+        "purple-400" => PURPLE_400,
+        "purple-500" => PURPLE_500,
+        "purple-600" => PURPLE_600,
+        "purple-700" => PURPLE_700,
+        "purple-800" => PURPLE_800,
+        "purple-900" => PURPLE_900,
+        "purple-950" => PURPLE_950,
+        "fuchsia-50" => FUCHSIA_50,
+        "fuchsia-100" => FUCHSIA_100,
+        "fuchsia-200" => FUCHSIA_200,
+        "fuchsia-300" => FUCHSIA_300,
+        "fuchsia-400" => FUCHSIA_400,
+        "fuchsia-500" => FUCHSIA_500,
+        "fuchsia-600" => FUCHSIA_600,
+        "fuchsia-700" => FUCHSIA_700,
+        "fuchsia-800" => FUCHSIA_800,
+        "fuchsia-900" => FUCHSIA_900,
+        "fuchsia-950" => FUCHSIA_950,
+        "pink-50" => PINK_50,
+        "pink-100" => PINK_100,
+        "pink-200" => PINK_200,
+        "pink-300" => PINK_300,
+        "pink-400" => PINK_400,
+        "pink-500" => PINK_500,
+        "pink-600" => PINK_600,
+        "pink-700" => PINK_700,
+        "pink-800" => PINK_800,
+        "pink-900" => PINK_900,
+        "pink-950" => PINK_950,
+        "rose-50" => ROSE_50,
+        "rose-100" => ROSE_100,
+        "rose-200" => ROSE_200,
+        "rose-300" => ROSE_300,
+        "rose-400" => ROSE_400,
+        "rose-500" => ROSE_500,
+        "rose-600" => ROSE_600,
+        "rose-700" => ROSE_700,
+        "rose-800" => ROSE_800,
+        "rose-900" => ROSE_900,
+        "rose-950" => ROSE_950,
+        _ => return None,
+    })
+}
+
+/// Looks up a CSS named color, e.g. `"rebeccapurple"`.
+pub fn css(name: &str) -> Option<&'static str> {
+    Some(match name {
+        "aliceblue" => "#f0f8ff",
+        "antiquewhite" => "#faebd7",
+        "aqua" => "#00ffff",
+        "aquamarine" => "#7fffd4",
+        "azure" => "#f0ffff",
+        "beige" => "#f5f5dc",
+        "bisque" => "#ffe4c4",
+        "black" => "#000000",
+        "blanchedalmond" => "#ffebcd",
+        "blue" => "#0000ff",
+        "blueviolet" => "#8a2be2",
+        "brown" => "#a52a2a",
+        "burlywood" => "#deb887",
+        "cadetblue" => "#5f9ea0",
+        "chartreuse" => "#7fff00",
+        "chocolate" => "#d2691e",
+        "coral" => "#ff7f50",
+        "cornflowerblue" => "#6495ed",
+        "cornsilk" => "#fff8dc",
+        "crimson" => "#dc143c",
+        "cyan" => "#00ffff",
+        "darkblue" => "#00008b",
+        "darkcyan" => "#008b8b",
+        "darkgoldenrod" => "#b8860b",
+        "darkgray" => "#a9a9a9",
+        "darkgreen" => "#006400",
+        "darkgrey" => "#a9a9a9",
+        "darkkhaki" => "#bdb76b",
+        "darkmagenta" => "#8b008b",
+        "darkolivegreen" => "#556b2f",
+        "darkorange" => "#ff8c00",
+        "darkorchid" => "#9932cc",
+        "darkred" => "#8b0000",
+        "darksalmon" => "#e9967a",
+        "darkseagreen" => "#8fbc8f",
+        "darkslateblue" => "#483d8b",
+        "darkslategray" => "#2f4f4f",
+        "darkslategrey" => "#2f4f4f",
+        "darkturquoise" => "#00ced1",
+        "darkviolet" => "#9400d3",
+        "deeppink" => "#ff1493",
+        "deepskyblue" => "#00bfff",
+        "dimgray" => "#696969",
+        "dimgrey" => "#696969",
+        "dodgerblue" => "#1e90ff",
+        "firebrick" => "#b22222",
+        "floralwhite" => "#fffaf0",
+        "forestgreen" => "#228b22",
+        "fuchsia" => "#ff00ff",
+        "gainsboro" => "#dcdcdc",
+        "ghostwhite" => "#f8f8ff",
+        "gold" => "#ffd700",
+        "goldenrod" => "#daa520",
+        "gray" => "#808080",
+        "green" => "#008000",
+        "greenyellow" => "#adff2f",
+        "grey" => "#808080",
+        "honeydew" => "#f0fff0",
+        "hotpink" => "#ff69b4",
+        "indianred" => "#cd5c5c",
+        "indigo" => "#4b0082",
+        "ivory" => "#fffff0",
+        "khaki" => "#f0e68c",
+        "lavender" => "#e6e6fa",
+        "lavenderblush" => "#fff0f5",
+        "lawngreen" => "#7cfc00",
+        "lemonchiffon" => "#fffacd",
+        "lightblue" => "#add8e6",
+        "lightcoral" => "#f08080",
+        "lightcyan" => "#e0ffff",
+        "lightgoldenrodyellow" => "#fafad2",
+        "lightgray" => "#d3d3d3",
+        "lightgreen" => "#90ee90",
+        "lightgrey" => "#d3d3d3",
+        "lightpink" => "#ffb6c1",
+        "lightsalmon" => "#ffa07a",
+        "lightseagreen" => "#20b2aa",
+        "lightskyblue" => "#87cefa",
+        "lightslategray" => "#778899",
+        "lightslategrey" => "#778899",
+        "lightsteelblue" => "#b0c4de",
+        "lightyellow" => "#ffffe0",
+        "lime" => "#00ff00",
+        "limegreen" => "#32cd32",
+        "linen" => "#faf0e6",
+        "magenta" => "#ff00ff",
+        "maroon" => "#800000",
+        "mediumaquamarine" => "#66cdaa",
+        "mediumblue" => "#0000cd",
+        "mediumorchid" => "#ba55d3",
+        "mediumpurple" => "#9370db",
+        "mediumseagreen" => "#3cb371",
+        "mediumslateblue" => "#7b68ee",
+        "mediumspringgreen" => "#00fa9a",
+        "mediumturquoise" => "#48d1cc",
+        "mediumvioletred" => "#c71585",
+        "midnightblue" => "#191970",
+        "mintcream" => "#f5fffa",
+        "mistyrose" => "#ffe4e1",
+        "moccasin" => "#ffe4b5",
+        "navajowhite" => "#ffdead",
+        "navy" => "#000080",
+        "oldlace" => "#fdf5e6",
+        "olive" => "#808000",
+        "olivedrab" => "#6b8e23",
+        "orange" => "#ffa500",
+        "orangered" => "#ff4500",
+        "orchid" => "#da70d6",
+        "palegoldenrod" => "#eee8aa",
+        "palegreen" => "#98fb98",
+        "paleturquoise" => "#afeeee",
+        "palevioletred" => "#db7093",
+        "papayawhip" => "#ffefd5",
+        "peachpuff" => "#ffdab9",
+        "peru" => "#cd853f",
+        "pink" => "#ffc0cb",
+        "plum" => "#dda0dd",
+        "powderblue" => "#b0e0e6",
+        "purple" => "#800080",
+        "rebeccapurple" => "#663399",
+        "red" => "#ff0000",
+        "rosybrown" => "#bc8f8f",
+        "royalblue" => "#4169e1",
+        "saddlebrown" => "#8b4513",
+        "salmon" => "#fa8072",
+        "sandybrown" => "#f4a460",
+        "seagreen" => "#2e8b57",
+        "seashell" => "#fff5ee",
+        "sienna" => "#a0522d",
+        "silver" => "#c0c0c0",
+        "skyblue" => "#87ceeb",
+        "slateblue" => "#6a5acd",
+        "slategray" => "#708090",
+        "slategrey" => "#708090",
+        "snow" => "#fffafa",
+        "springgreen" => "#00ff7f",
+        "steelblue" => "#4682b4",
+        "tan" => "#d2b48c",
+        "teal" => "#008080",
+        "thistle" => "#d8bfd8",
+        "tomato" => "#ff6347",
+        "turquoise" => "#40e0d0",
+        "violet" => "#ee82ee",
+        "wheat" => "#f5deb3",
+        "white" => "#ffffff",
+        "whitesmoke" => "#f5f5f5",
+        "yellow" => "#ffff00",
+        "yellowgreen" => "#9acd32",
+        "transparent" => TRANSPARENT,
+        _ => return None,
+    })
+}
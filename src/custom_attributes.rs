@@ -0,0 +1,198 @@
+use crate::parse_attributes::AttributeInput;
+use bevy::{
+    asset::{Assets, Handle},
+    ecs::{component::Component, system::Resource, world::EntityWorldMut},
+    ui::UiMaterial,
+    utils::HashMap,
+};
+use std::{
+    any::{type_name, Any, TypeId},
+    rc::Rc,
+    sync::Arc,
+};
+
+/// Applies a parsed attribute value to an element's entity.
+pub type ApplyAttribute = Rc<dyn Fn(&mut EntityWorldMut)>;
+
+/// A user-defined attribute, registered with [`crate::DioxusUiPlugin::with_custom_attribute`] or
+/// [`crate::DioxusUiPlugin::with_material_attribute`].
+#[derive(Clone)]
+pub struct CustomAttribute {
+    parse: Arc<dyn Fn(AttributeInput) -> Result<ApplyAttribute, String> + Send + Sync>,
+    /// The component the element must already have for the attribute to apply.
+    pub required_component: Option<TypeId>,
+}
+
+impl CustomAttribute {
+    /// If the element's entity has no `C` component yet, a default one is inserted first.
+    pub fn new<C: Component + Default, T: Clone + 'static>(
+        parse: fn(&str) -> Result<T, String>,
+        set: fn(&mut C, T),
+    ) -> Self {
+        Self {
+            parse: Arc::new(
+                move |value: AttributeInput| -> Result<ApplyAttribute, String> {
+                    let value = parse_value(value, parse)?;
+                    Ok(Rc::new(move |entity: &mut EntityWorldMut| {
+                        if !entity.contains::<C>() {
+                            entity.insert(C::default());
+                        }
+                        set(&mut entity.get_mut::<C>().unwrap(), value.clone());
+                    }))
+                },
+            ),
+            required_component: None,
+        }
+    }
+
+    /// Writes to the material of a `material_node` element using `M`.
+    pub fn for_material<M: UiMaterial, T: Clone + 'static>(
+        parse: fn(&str) -> Result<T, String>,
+        set: fn(&mut M, T),
+    ) -> Self {
+        Self {
+            parse: Arc::new(
+                move |value: AttributeInput| -> Result<ApplyAttribute, String> {
+                    let value = parse_value(value, parse)?;
+                    Ok(Rc::new(move |entity: &mut EntityWorldMut| {
+                        let Some(handle) = entity.get::<Handle<M>>().cloned() else {
+                            return;
+                        };
+                        entity.world_scope(|world| {
+                            if let Some(material) =
+                                world.resource_mut::<Assets<M>>().get_mut(&handle)
+                            {
+                                set(material, value.clone());
+                            }
+                        });
+                    }))
+                },
+            ),
+            required_component: Some(TypeId::of::<Handle<M>>()),
+        }
+    }
+
+    /// Parses the value once, so that the result can be applied to every spawned template.
+    pub fn parse(&self, value: AttributeInput) -> Result<ApplyAttribute, String> {
+        (self.parse)(value)
+    }
+}
+
+fn parse_value<T: Clone + 'static>(
+    value: AttributeInput,
+    parse: fn(&str) -> Result<T, String>,
+) -> Result<T, String> {
+    match value {
+        AttributeInput::Text(value) => parse(value),
+        AttributeInput::Any(value) => match value.downcast_ref::<T>() {
+            Some(value) => Ok(value.clone()),
+            None => Err(format!("expected `{}`", type_name::<T>())),
+        },
+        _ => convert_literal(value).ok_or_else(|| format!("expected `{}`", type_name::<T>())),
+    }
+}
+
+/// Converts a number or bool passed directly to `T`, if `T` is a primitive type that can hold it.
+fn convert_literal<T: 'static>(value: AttributeInput) -> Option<T> {
+    let candidates: Vec<Box<dyn Any>> = match value {
+        AttributeInput::Bool(value) => vec![Box::new(value)],
+        AttributeInput::Float(value) => vec![Box::new(value), Box::new(value as f32)],
+        AttributeInput::Int(value) => {
+            let mut candidates: Vec<Box<dyn Any>> = vec![
+                Box::new(value),
+                Box::new(value as f64),
+                Box::new(value as f32),
+            ];
+            if let Ok(value) = i32::try_from(value) {
+                candidates.push(Box::new(value));
+            }
+            if let Ok(value) = u32::try_from(value) {
+                candidates.push(Box::new(value));
+            }
+            if let Ok(value) = usize::try_from(value) {
+                candidates.push(Box::new(value));
+            }
+            candidates
+        }
+        AttributeInput::Text(_) | AttributeInput::Any(_) => return None,
+    };
+    candidates
+        .into_iter()
+        .find_map(|candidate| candidate.downcast::<T>().ok())
+        .map(|value| *value)
+}
+
+/// Custom attributes by the material handle type they were registered for, if any, and name.
+#[derive(Resource, Default, Clone)]
+pub struct CustomAttributes(HashMap<(Option<TypeId>, &'static str), CustomAttribute>);
+
+impl CustomAttributes {
+    /// Panics if the attribute is already registered for the same material or for every element.
+    pub fn insert(&mut self, name: &'static str, custom_attribute: CustomAttribute) {
+        let key = (custom_attribute.required_component, name);
+        assert!(
+            !self.0.contains_key(&key),
+            "the custom attribute `{name}` is already registered"
+        );
+        self.0.insert(key, custom_attribute);
+    }
+
+    /// Looks up the attribute for an element using the `material` handle type, if any, preferring
+    /// one registered for that material. Returns an error if the attribute is only registered for
+    /// other materials, and `None` if it isn't a custom attribute.
+    pub fn get(
+        &self,
+        name: &'static str,
+        material: Option<TypeId>,
+    ) -> Option<Result<&CustomAttribute, String>> {
+        if let Some(custom_attribute) = material
+            .and_then(|material| self.0.get(&(Some(material), name)))
+            .or_else(|| self.0.get(&(None, name)))
+        {
+            return Some(Ok(custom_attribute));
+        }
+        self.0
+            .keys()
+            .any(|(_, registered_name)| *registered_name == name)
+            .then(|| Err(MATERIAL_ATTRIBUTE_ERROR.to_owned()))
+    }
+
+    #[cfg(feature = "hot_reload")]
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.0.keys().map(|(_, name)| *name)
+    }
+}
+
+const MATERIAL_ATTRIBUTE_ERROR: &str =
+    "only supported on `material_node` elements using a material it was registered for";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse<T: std::str::FromStr>(value: &str) -> Result<T, String> {
+        value.parse::<T>().map_err(|_| format!("invalid `{value}`"))
+    }
+
+    #[test]
+    fn converts_numbers_and_bools_passed_directly() {
+        assert_eq!(
+            parse_value(AttributeInput::Float(0.5), parse::<f32>),
+            Ok(0.5)
+        );
+        assert_eq!(
+            parse_value(AttributeInput::Float(0.5), parse::<f64>),
+            Ok(0.5)
+        );
+        assert_eq!(parse_value(AttributeInput::Int(3), parse::<f32>), Ok(3.0));
+        assert_eq!(parse_value(AttributeInput::Int(3), parse::<usize>), Ok(3));
+        assert_eq!(
+            parse_value(AttributeInput::Bool(true), parse::<bool>),
+            Ok(true)
+        );
+        assert_eq!(parse_value(AttributeInput::Text("2"), parse::<i32>), Ok(2));
+        assert!(parse_value(AttributeInput::Int(-1), parse::<usize>).is_err());
+        assert!(parse_value(AttributeInput::Float(0.5), parse::<i32>).is_err());
+        assert!(parse_value(AttributeInput::Bool(true), parse::<String>).is_err());
+    }
+}
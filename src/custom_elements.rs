@@ -0,0 +1,26 @@
+use bevy::{
+    ecs::{component::Component, system::Resource, world::EntityWorldMut},
+    prelude::{Deref, DerefMut},
+    utils::HashMap,
+};
+use dioxus::dioxus_core::AttributeValue;
+
+/// A user-defined element, registered with [`crate::DioxusUiPlugin::with_custom_element`].
+///
+/// Custom elements are spawned as a regular `node`, and so support every node attribute.
+#[derive(Clone, Copy)]
+pub struct CustomElement {
+    /// Attributes handled by `set_attribute` instead of the built-in node attributes.
+    pub attributes: &'static [&'static str],
+    /// Inserts the element's own components onto the newly spawned node entity.
+    pub spawn: fn(&mut EntityWorldMut),
+    /// Applies one of `attributes` to the element's entity, returning why the value was rejected
+    /// if it is invalid, which is reported as a [`crate::prelude::UiDiagnostic`].
+    pub set_attribute: fn(&str, &AttributeValue, &mut EntityWorldMut) -> Result<(), String>,
+}
+
+#[derive(Resource, Deref, DerefMut, Default, Clone)]
+pub struct CustomElements(pub HashMap<&'static str, CustomElement>);
+
+#[derive(Component)]
+pub struct CustomElementTag(pub &'static str);
@@ -0,0 +1,123 @@
+use crate::ecs_hooks::with_world;
+use bevy::{
+    ecs::{
+        bundle::Bundle,
+        entity::Entity,
+        event::Event,
+        system::{Command, CommandQueue, Resource},
+        world::World,
+    },
+    hierarchy::DespawnRecursive,
+};
+use std::{
+    marker::PhantomData,
+    mem,
+    sync::{Arc, Mutex},
+};
+
+/// Commands queued by components, applied at the end of `tick_dioxus_ui`.
+#[derive(Resource, Default, Clone)]
+pub struct DeferredCommandQueue(Arc<Mutex<CommandQueue>>);
+
+impl DeferredCommandQueue {
+    pub fn push(&self, command: impl Command) {
+        self.0.lock().unwrap().push(command);
+    }
+
+    pub fn apply(&self, world: &mut World) {
+        // Commands may queue more commands, so the lock isn't held while they are applied
+        let mut queue = mem::take(&mut *self.0.lock().unwrap());
+        queue.apply(world);
+    }
+}
+
+/// Sends `E` events from event handlers, which are written to `Events<E>` at the end of the
+/// current `tick_dioxus_ui`.
+pub struct UseEventWriter<E: Event> {
+    queue: DeferredCommandQueue,
+    marker: PhantomData<fn(E)>,
+}
+
+impl<E: Event> Clone for UseEventWriter<E> {
+    fn clone(&self) -> Self {
+        Self {
+            queue: self.queue.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<E: Event> UseEventWriter<E> {
+    pub fn send(&self, event: E) {
+        self.queue.push(move |world: &mut World| {
+            world.send_event(event);
+        });
+    }
+}
+
+pub fn use_event_writer<E: Event>() -> UseEventWriter<E> {
+    UseEventWriter {
+        queue: with_world(|world| world.resource::<DeferredCommandQueue>().clone()),
+        marker: PhantomData,
+    }
+}
+
+/// Spawns, modifies and despawns entities from event handlers, like Bevy's `Commands`. The
+/// commands are applied at the end of the current `tick_dioxus_ui`.
+///
+/// Commands on entities that no longer exist are ignored.
+#[derive(Clone)]
+pub struct UseCommands {
+    queue: DeferredCommandQueue,
+}
+
+impl UseCommands {
+    pub fn add(&self, command: impl Command) {
+        self.queue.push(command);
+    }
+
+    pub fn spawn<B: Bundle>(&self, bundle: B) {
+        self.spawn_then(bundle, |_| {});
+    }
+
+    /// Spawns an entity, then passes it to `on_spawn` once the command is applied, e.g. to store
+    /// it in a signal.
+    pub fn spawn_then<B: Bundle>(&self, bundle: B, on_spawn: impl FnOnce(Entity) + Send + 'static) {
+        self.queue.push(move |world: &mut World| {
+            on_spawn(world.spawn(bundle).id());
+        });
+    }
+
+    pub fn insert<B: Bundle>(&self, entity: Entity, bundle: B) {
+        self.queue.push(move |world: &mut World| {
+            if let Some(mut entity) = world.get_entity_mut(entity) {
+                entity.insert(bundle);
+            }
+        });
+    }
+
+    pub fn remove<B: Bundle>(&self, entity: Entity) {
+        self.queue.push(move |world: &mut World| {
+            if let Some(mut entity) = world.get_entity_mut(entity) {
+                entity.remove::<B>();
+            }
+        });
+    }
+
+    pub fn despawn(&self, entity: Entity) {
+        self.queue.push(move |world: &mut World| {
+            world.despawn(entity);
+        });
+    }
+
+    /// Despawns the entity along with its children.
+    pub fn despawn_recursive(&self, entity: Entity) {
+        self.queue.push(DespawnRecursive { entity });
+    }
+}
+
+pub fn use_commands() -> UseCommands {
+    UseCommands {
+        queue: with_world(|world| world.resource::<DeferredCommandQueue>().clone()),
+    }
+}
@@ -1,14 +1,24 @@
-use crate::ecs_hooks::EcsContext;
-use bevy::ecs::system::{IntoSystem, Resource, System};
+use crate::ecs_hooks::with_world;
+use bevy::{
+    ecs::{
+        system::{IntoSystem, Resource, System, SystemId},
+        world::World,
+    },
+    log::warn,
+};
+use dioxus::prelude::{use_drop, use_hook};
+use std::sync::{Arc, Mutex};
+
+pub type DeferredSystemRun = Box<dyn FnOnce(&mut World) + Send>;
 
 #[derive(Resource, Default)]
 pub struct DeferredSystemRunQueue {
-    pub run_queue: Box<Vec<Box<dyn System<In = (), Out = ()>>>>,
+    pub run_queue: Arc<Mutex<Vec<DeferredSystemRun>>>,
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 pub struct DeferredSystemScheduler {
-    run_queue: *mut Vec<Box<dyn System<In = (), Out = ()>>>,
+    run_queue: Arc<Mutex<Vec<DeferredSystemRun>>>,
 }
 
 impl DeferredSystemScheduler {
@@ -17,19 +27,109 @@ impl DeferredSystemScheduler {
         S: IntoSystem<(), (), M> + 'static,
         M: 'static,
     {
-        unsafe { &mut *self.run_queue }.push(Box::new(S::into_system(system)));
+        self.schedule_with_input(system, ());
+    }
+
+    pub fn schedule_with_input<I, S, M>(&self, system: S, input: I)
+    where
+        I: Send + Sync + 'static,
+        S: IntoSystem<I, (), M> + 'static,
+        M: 'static,
+    {
+        self.schedule_with_callback(system, input, |()| {});
+    }
+
+    /// Runs the system with `input`, then passes its output to `on_output`, e.g. to store it in a
+    /// signal.
+    pub fn schedule_with_callback<I, O, S, M>(
+        &self,
+        system: S,
+        input: I,
+        on_output: impl FnOnce(O) + Send + 'static,
+    ) where
+        I: Send + Sync + 'static,
+        O: 'static,
+        S: IntoSystem<I, O, M> + 'static,
+        M: 'static,
+    {
+        let mut system = S::into_system(system);
+        self.push(move |world| {
+            system.initialize(world);
+            on_output(system.run(input, world));
+        });
     }
-}
 
-unsafe impl Send for DeferredSystemScheduler {}
-unsafe impl Sync for DeferredSystemScheduler {}
+    fn push(&self, run: impl FnOnce(&mut World) + Send + 'static) {
+        self.run_queue.lock().unwrap().push(Box::new(run));
+    }
+}
 
 pub fn use_system_scheduler() -> DeferredSystemScheduler {
     DeferredSystemScheduler {
-        run_queue: Box::as_mut(
-            &mut EcsContext::get_world()
-                .resource_mut::<DeferredSystemRunQueue>()
-                .run_queue,
-        ),
+        run_queue: with_world(|world| {
+            Arc::clone(&world.resource::<DeferredSystemRunQueue>().run_queue)
+        }),
     }
 }
+
+/// A system registered with `World::register_system` by [`use_system`], which keeps its `Local`s
+/// and query state between runs.
+pub struct UseSystem<I: 'static = (), O: 'static = ()> {
+    id: SystemId<I, O>,
+    scheduler: DeferredSystemScheduler,
+}
+
+impl<I: 'static, O: 'static> Clone for UseSystem<I, O> {
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id,
+            scheduler: self.scheduler.clone(),
+        }
+    }
+}
+
+impl UseSystem {
+    pub fn run(&self) {
+        self.run_with_input(());
+    }
+}
+
+impl<I: Send + Sync + 'static, O: 'static> UseSystem<I, O> {
+    pub fn id(&self) -> SystemId<I, O> {
+        self.id
+    }
+
+    pub fn run_with_input(&self, input: I) {
+        self.run_with_callback(input, |_| {});
+    }
+
+    /// Runs the system with `input` before the next render, then passes its output to
+    /// `on_output`.
+    pub fn run_with_callback(&self, input: I, on_output: impl FnOnce(O) + Send + 'static) {
+        let id = self.id;
+        self.scheduler
+            .push(move |world| match world.run_system_with_input(id, input) {
+                Ok(output) => on_output(output),
+                Err(error) => warn!("Failed to run a system registered by `use_system`: {error}"),
+            });
+    }
+}
+
+/// Registers the system on the first render, and unregisters it when the component is dropped.
+pub fn use_system<I, O, S, M>(system: S) -> UseSystem<I, O>
+where
+    I: 'static,
+    O: 'static,
+    S: IntoSystem<I, O, M> + 'static,
+{
+    let id = use_hook(|| with_world(|world| world.register_system(system)));
+    let scheduler = use_system_scheduler();
+    let dropped_scheduler = scheduler.clone();
+    use_drop(move || {
+        dropped_scheduler.push(move |world| {
+            let _ = world.remove_system(id);
+        });
+    });
+
+    UseSystem { id, scheduler }
+}
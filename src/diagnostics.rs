@@ -0,0 +1,38 @@
+use bevy::{
+    ecs::{event::Event, world::World},
+    log::warn,
+};
+use std::fmt::{self, Display, Formatter};
+
+/// An element, attribute or event listener that bevy_dioxus could not apply, and so ignored.
+///
+/// Diagnostics are logged as warnings, and sent as events.
+#[derive(Event, Clone, Debug)]
+pub struct UiDiagnostic {
+    /// Tags of the element and its ancestors, e.g. `node > node > text`, from the UI root, or from
+    /// the template root for attributes found invalid while loading a template.
+    pub element_path: String,
+    /// The attribute or event listener name, if the diagnostic is not about the element itself.
+    pub attribute: Option<String>,
+    pub value: Option<String>,
+    pub reason: String,
+}
+
+impl UiDiagnostic {
+    pub(crate) fn report(self, world: &mut World) {
+        warn!("{self}");
+        world.send_event(self);
+    }
+}
+
+impl Display for UiDiagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "bevy_dioxus ignored `{}`", self.element_path)?;
+        match (&self.attribute, &self.value) {
+            (Some(attribute), Some(value)) => write!(f, " attribute `{attribute}: {value}`")?,
+            (Some(attribute), None) => write!(f, " `{attribute}`")?,
+            _ => {}
+        }
+        write!(f, ": {}.", self.reason)
+    }
+}
@@ -1,116 +1,338 @@
 use crate::UiContext;
 use bevy::{
+    asset::{Asset, AssetEvent, AssetServer, Assets, Handle, LoadState},
     ecs::{
         component::ComponentId,
+        event::{Event, Events},
         // event::{Event, EventIterator, Events, ManualEventReader},
-        query::{QueryFilter, ReadOnlyQueryData},
+        query::{QueryFilter, ReadOnlyQueryData, With},
         system::{Query, Resource, SystemState},
         world::World,
     },
+    math::Vec2,
     utils::{HashMap, HashSet},
+    window::{PrimaryWindow, Window, WindowResized},
 };
 use dioxus::{
     dioxus_core::{use_hook, ScopeId},
-    prelude::{consume_context, current_scope_id, use_drop},
+    prelude::{current_scope_id, use_drop},
+};
+use std::{
+    any::TypeId,
+    cell::{Cell, RefCell},
+    ptr::NonNull,
+    rc::Rc,
 };
-use std::any::TypeId;
 
 #[derive(Default)]
 pub(crate) struct EcsSubscriptions {
-    pub resources: Box<HashMap<ComponentId, HashSet<ScopeId>>>,
-    #[allow(clippy::type_complexity)]
-    pub events: Box<HashMap<TypeId, (Box<dyn Fn(&World) -> bool>, HashSet<ScopeId>)>>,
-    pub world_and_queries: Box<HashSet<ScopeId>>,
+    pub resources: HashMap<ComponentId, HashSet<ScopeId>>,
+    pub events: EventSubscriptions,
+    pub world_and_queries: HashSet<ScopeId>,
+    pub loading_assets: HashSet<ScopeId>,
+}
+
+pub(crate) type EventSubscriptions =
+    HashMap<TypeId, (Box<dyn Fn(&World) -> bool>, HashSet<ScopeId>)>;
+
+thread_local! {
+    static WORLD: Cell<Option<WorldAccess>> = const { Cell::new(None) };
+}
+
+#[derive(Clone, Copy)]
+struct WorldAccess {
+    world: NonNull<World>,
+    mutable: bool,
+    /// Like `RefCell`, the number of shared borrows of the lent world, or -1 while it is borrowed
+    /// mutably.
+    borrows: isize,
+}
+
+const NO_WORLD_ACCESS: &str =
+    "ECS hooks and `EcsRef`s can only be used while a `DioxusUiRoot` is rendering or handling events";
+
+/// Lends the world to ECS hooks, [`EcsRef`]s and the mutation applier while `f` runs.
+pub(crate) fn with_world_access<R>(world: &mut World, f: impl FnOnce() -> R) -> R {
+    lend_world(NonNull::from(world), true, f)
 }
 
-#[derive(Clone)]
-pub(crate) struct EcsContext {
-    pub world: *mut World,
+/// Lends the world to [`EcsRef`]s while `f` runs, e.g. for event handlers, which have to write to
+/// the world through deferred hooks like `use_commands`.
+pub(crate) fn with_world_read_access<R>(world: &World, f: impl FnOnce() -> R) -> R {
+    lend_world(NonNull::from(world), false, f)
 }
 
-impl EcsContext {
-    pub fn get_world<'a>() -> &'a mut World {
-        unsafe { &mut *consume_context::<EcsContext>().world }
+fn lend_world<R>(world: NonNull<World>, mutable: bool, f: impl FnOnce() -> R) -> R {
+    // Restores the previous access even if `f` panics, so that the world is never left lent out
+    struct Restore(Option<WorldAccess>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            WORLD.set(self.0);
+        }
     }
+
+    let _restore = Restore(WORLD.replace(Some(WorldAccess {
+        world,
+        mutable,
+        borrows: 0,
+    })));
+    f()
 }
 
-pub fn use_world<'a>() -> &'a World {
-    let world = EcsContext::get_world();
+/// Runs `f` with the world lent by `with_world_access`, panicking if there is none or it is
+/// already borrowed.
+pub(crate) fn with_world<R>(f: impl FnOnce(&mut World) -> R) -> R {
+    let (mut world, _borrow) = borrow_world(true);
+    // SAFETY: The world was lent from a `&mut World` that isn't used until the lend ends, no other
+    // reference to it exists while it is borrowed mutably, and the reference can't escape `f`
+    f(unsafe { world.as_mut() })
+}
 
-    let scope_id = current_scope_id().unwrap();
-    let subscription_manager = use_hook(|| {
-        let subscription_manager = &mut world
-            .non_send_resource_mut::<UiContext>()
-            .subscriptions
-            .world_and_queries;
-        subscription_manager.insert(scope_id);
-        Box::as_mut(subscription_manager) as *mut HashSet<ScopeId>
-    });
-    use_drop(move || {
-        unsafe { &mut *subscription_manager }.remove(&scope_id);
-    });
+/// Runs `f` with the lent world, panicking if there is none or it is borrowed mutably.
+pub(crate) fn with_world_ref<R>(f: impl FnOnce(&World) -> R) -> R {
+    let (world, _borrow) = borrow_world(false);
+    // SAFETY: The world outlives the lend, it can't be borrowed mutably until `_borrow` is
+    // dropped, and the reference can't escape `f`
+    f(unsafe { world.as_ref() })
+}
+
+fn borrow_world(mutable: bool) -> (NonNull<World>, WorldBorrow) {
+    let mut access = WORLD.get().expect(NO_WORLD_ACCESS);
+    if mutable {
+        assert!(
+            access.mutable,
+            "ECS hooks can't be used while handling events"
+        );
+        assert!(access.borrows == 0, "the world is already borrowed");
+        access.borrows = -1;
+    } else {
+        assert!(access.borrows >= 0, "the world is already borrowed mutably");
+        access.borrows += 1;
+    }
+    WORLD.set(Some(access));
+    (access.world, WorldBorrow { mutable })
+}
 
-    world
+/// Releases a borrow of the lent world when dropped, even if the borrower panics.
+struct WorldBorrow {
+    mutable: bool,
 }
 
-pub fn use_resource<'a, T: Resource>() -> &'a T {
-    let world = EcsContext::get_world();
+impl Drop for WorldBorrow {
+    fn drop(&mut self) {
+        if let Some(mut access) = WORLD.get() {
+            access.borrows = if self.mutable { 0 } else { access.borrows - 1 };
+            WORLD.set(Some(access));
+        }
+    }
+}
+
+/// A reference into the world returned by ECS hooks, which is looked up again whenever it is
+/// read.
+///
+/// It can be kept in event handlers and futures, but can only be read while a UI is rendering or
+/// handling events.
+pub struct EcsRef<T: ?Sized + 'static> {
+    get: Rc<dyn Fn(&World) -> &T>,
+}
+
+impl<T: ?Sized + 'static> EcsRef<T> {
+    fn new(get: impl Fn(&World) -> &T + 'static) -> Self {
+        Self { get: Rc::new(get) }
+    }
 
-    let resource_id = world.components().resource_id::<T>().unwrap();
+    /// Runs `f` with the current value, panicking unless a UI is rendering or handling events.
+    pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        with_world_ref(|world| f((self.get)(world)))
+    }
+}
+
+impl<T: ?Sized + 'static> Clone for EcsRef<T> {
+    fn clone(&self) -> Self {
+        Self {
+            get: Rc::clone(&self.get),
+        }
+    }
+}
+
+/// Subscribes the current scope with `subscribe` on the first render, and unsubscribes it with
+/// `unsubscribe` when it is dropped.
+fn use_subscription(
+    subscribe: impl FnOnce(&mut EcsSubscriptions, ScopeId),
+    unsubscribe: impl FnOnce(&mut EcsSubscriptions, ScopeId) + 'static,
+) -> Rc<RefCell<EcsSubscriptions>> {
     let scope_id = current_scope_id().unwrap();
-    let subscription_manager = use_hook(|| {
-        let subscription_manager = &mut world
-            .non_send_resource_mut::<UiContext>()
-            .subscriptions
-            .resources;
-        subscription_manager
-            .entry(resource_id)
-            .or_default()
-            .insert(scope_id);
-        Box::as_mut(subscription_manager) as *mut HashMap<ComponentId, HashSet<ScopeId>>
+    let subscriptions = use_hook(|| {
+        let subscriptions =
+            with_world(|world| Rc::clone(&world.non_send_resource::<UiContext>().subscriptions));
+        subscribe(&mut subscriptions.borrow_mut(), scope_id);
+        subscriptions
     });
-    use_drop(move || {
-        let subscription_manager = &mut unsafe { &mut *subscription_manager };
-        let resource_subscriptions = subscription_manager.get_mut(&resource_id).unwrap();
-        resource_subscriptions.remove(&scope_id);
-        if resource_subscriptions.is_empty() {
-            subscription_manager.remove(&resource_id);
+    let dropped_subscriptions = Rc::clone(&subscriptions);
+    use_drop(move || unsubscribe(&mut dropped_subscriptions.borrow_mut(), scope_id));
+    subscriptions
+}
+
+fn use_world_subscription() {
+    use_subscription(
+        |subscriptions, scope_id| {
+            subscriptions.world_and_queries.insert(scope_id);
+        },
+        |subscriptions, scope_id| {
+            subscriptions.world_and_queries.remove(&scope_id);
+        },
+    );
+}
+
+pub fn use_world() -> EcsRef<World> {
+    use_world_subscription();
+
+    EcsRef::new(|world| world)
+}
+
+pub fn use_resource<T: Resource>() -> EcsRef<T> {
+    let resource_id = with_world(|world| world.components().resource_id::<T>().unwrap());
+    use_subscription(
+        |subscriptions, scope_id| {
+            subscriptions
+                .resources
+                .entry(resource_id)
+                .or_default()
+                .insert(scope_id);
+        },
+        move |subscriptions, scope_id| {
+            let resource_subscriptions = subscriptions.resources.get_mut(&resource_id).unwrap();
+            resource_subscriptions.remove(&scope_id);
+            if resource_subscriptions.is_empty() {
+                subscriptions.resources.remove(&resource_id);
+            }
+        },
+    );
+
+    EcsRef::new(|world| world.resource::<T>())
+}
+
+/// Returns the logical size of the primary window, re-rendering when it is resized.
+pub fn use_window_size() -> Vec2 {
+    use_event_subscription::<WindowResized>();
+
+    with_world(|world| {
+        world
+            .query_filtered::<&Window, With<PrimaryWindow>>()
+            .get_single(world)
+            .map(|window| Vec2::new(window.width(), window.height()))
+            .unwrap_or_default()
+    })
+}
+
+/// Returns the asset, re-rendering when any `A` asset is loaded or changed.
+pub fn use_asset<A: Asset>(handle: &Handle<A>) -> UseAsset<A> {
+    use_event_subscription::<AssetEvent<A>>();
+
+    UseAsset {
+        handle: handle.clone(),
+    }
+}
+
+/// An asset returned by [`use_asset`], which is looked up again whenever it is read.
+pub struct UseAsset<A: Asset> {
+    handle: Handle<A>,
+}
+
+impl<A: Asset> UseAsset<A> {
+    /// Runs `f` with the asset, or `None` if it isn't loaded (anymore), panicking unless a UI is
+    /// rendering or handling events.
+    pub fn with<R>(&self, f: impl FnOnce(Option<&A>) -> R) -> R {
+        with_world_ref(|world| {
+            f(world
+                .get_resource::<Assets<A>>()
+                .and_then(|assets| assets.get(&self.handle)))
+        })
+    }
+}
+
+impl<A: Asset> Clone for UseAsset<A> {
+    fn clone(&self) -> Self {
+        Self {
+            handle: self.handle.clone(),
         }
+    }
+}
+
+/// Returns the load state of the asset, re-rendering every frame while it is loading, and when any
+/// `A` asset is loaded or changed.
+pub fn use_load_state<A: Asset>(handle: &Handle<A>) -> LoadState {
+    use_event_subscription::<AssetEvent<A>>();
+
+    let load_state = with_world(|world| {
+        world
+            .resource::<AssetServer>()
+            .get_load_state(handle)
+            .unwrap_or(LoadState::NotLoaded)
     });
 
-    world.resource()
+    // Failing to load doesn't send an `AssetEvent`, so the load state is polled until it settles
+    let scope_id = current_scope_id().unwrap();
+    let subscriptions = use_subscription(
+        |_, _| {},
+        |subscriptions, scope_id| {
+            subscriptions.loading_assets.remove(&scope_id);
+        },
+    );
+    let mut subscriptions = subscriptions.borrow_mut();
+    if load_state == LoadState::Loading {
+        subscriptions.loading_assets.insert(scope_id);
+    } else {
+        subscriptions.loading_assets.remove(&scope_id);
+    }
+
+    load_state
+}
+
+fn use_event_subscription<E: Event>() {
+    use_subscription(
+        |subscriptions, scope_id| {
+            subscriptions
+                .events
+                .entry(TypeId::of::<E>())
+                .or_insert_with(|| {
+                    let new_events_exist =
+                        |world: &World| !world.resource::<Events<E>>().is_empty();
+                    (
+                        Box::new(new_events_exist) as Box<dyn Fn(&World) -> bool>,
+                        HashSet::new(),
+                    )
+                })
+                .1
+                .insert(scope_id);
+        },
+        |subscriptions, scope_id| {
+            let (_, event_subscriptions) =
+                subscriptions.events.get_mut(&TypeId::of::<E>()).unwrap();
+            event_subscriptions.remove(&scope_id);
+            if event_subscriptions.is_empty() {
+                subscriptions.events.remove(&TypeId::of::<E>());
+            }
+        },
+    );
 }
 
-pub fn use_query<'a, Q>() -> UseQuery<'a, Q, ()>
+pub fn use_query<Q>() -> UseQuery<Q, ()>
 where
     Q: ReadOnlyQueryData,
 {
     use_query_filtered()
 }
 
-pub fn use_query_filtered<'a, Q, F>() -> UseQuery<'a, Q, F>
+pub fn use_query_filtered<Q, F>() -> UseQuery<Q, F>
 where
     Q: ReadOnlyQueryData,
     F: QueryFilter,
 {
-    let world = EcsContext::get_world();
-
-    let scope_id = current_scope_id().unwrap();
-    let subscription_manager = use_hook(|| {
-        let subscription_manager = &mut world
-            .non_send_resource_mut::<UiContext>()
-            .subscriptions
-            .world_and_queries;
-        subscription_manager.insert(scope_id);
-        Box::as_mut(subscription_manager) as *mut HashSet<ScopeId>
-    });
-    use_drop(move || {
-        unsafe { &mut *subscription_manager }.remove(&scope_id);
-    });
+    use_world_subscription();
 
     UseQuery {
-        system_state: SystemState::new(world),
-        world_ref: world,
+        system_state: with_world(SystemState::new),
     }
 }
 
@@ -119,21 +341,21 @@ where
 //     // TODO: Register the subscription
 
 //     let event_reader = use_hook(ManualEventReader::default);
-//     let events = EcsContext::get_world().resource::<Events<E>>();
+//     let events = with_world(|world| world.resource::<Events<E>>());
 //     event_reader.read(events)
 // }
 
-pub struct UseQuery<'a, Q: ReadOnlyQueryData + 'static, F: QueryFilter + 'static> {
+pub struct UseQuery<Q: ReadOnlyQueryData + 'static, F: QueryFilter + 'static> {
     system_state: SystemState<Query<'static, 'static, Q, F>>,
-    world_ref: &'a World,
 }
 
-impl<'a, Q, F> UseQuery<'a, Q, F>
+impl<Q, F> UseQuery<Q, F>
 where
     Q: ReadOnlyQueryData,
     F: QueryFilter,
 {
-    pub fn query(&mut self) -> Query<Q, F> {
-        self.system_state.get(self.world_ref)
+    /// Runs `f` with the query, panicking unless a UI is rendering or handling events.
+    pub fn query<R>(&mut self, f: impl FnOnce(Query<Q, F>) -> R) -> R {
+        with_world_ref(|world| f(self.system_state.get(world)))
     }
 }
@@ -1,70 +1,230 @@
+/// The registry of every built-in attribute, grouped by the element they apply to. `node`
+/// attributes apply to every element.
+///
+/// Each attribute is declared as either `name: Component.field = parser;`, which parses a string
+/// value and assigns it to a field of the component, or `name: Component => setter;`, for values
+/// that need more than a field assignment. Attributes marked `#[animatable]` hold values that can
+/// be interpolated, though animations aren't implemented yet.
+///
+/// The element declarations, the hot reload mapping and `set_attribute` are all generated from
+/// this list, so adding an attribute here is enough to make it usable everywhere.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! attributes {
+    (@$mode:ident $($argument:ident)?) => {
+        $crate::attributes! { @$mode [$($argument)?]
+            node {
+                components => set_components;
+                display: Style.display = parse_display;
+                position: Style.position_type = parse_position_type;
+                overflow: Style.overflow = parse_overflow;
+                overflow_x: Style.overflow.x = parse_overflow_axis;
+                overflow_y: Style.overflow.y = parse_overflow_axis;
+                direction: Style.direction = parse_direction;
+                #[animatable] left: Style.left = parse_val;
+                #[animatable] right: Style.right = parse_val;
+                #[animatable] top: Style.top = parse_val;
+                #[animatable] bottom: Style.bottom = parse_val;
+                #[animatable] width: Style.width = parse_val;
+                #[animatable] height: Style.height = parse_val;
+                #[animatable] min_width: Style.min_width = parse_val;
+                #[animatable] min_height: Style.min_height = parse_val;
+                #[animatable] max_width: Style.max_width = parse_val;
+                #[animatable] max_height: Style.max_height = parse_val;
+                aspect_ratio: Style.aspect_ratio = parse_aspect_ratio;
+                align_items: Style.align_items = parse_align_items;
+                justify_items: Style.justify_items = parse_justify_items;
+                align_self: Style.align_self = parse_align_self;
+                justify_self: Style.justify_self = parse_justify_self;
+                align_content: Style.align_content = parse_align_content;
+                justify_content: Style.justify_content = parse_justify_content;
+                #[animatable] margin: Style.margin = parse_ui_rect;
+                #[animatable] margin_left: Style.margin.left = parse_val;
+                #[animatable] margin_right: Style.margin.right = parse_val;
+                #[animatable] margin_top: Style.margin.top = parse_val;
+                #[animatable] margin_bottom: Style.margin.bottom = parse_val;
+                #[animatable] padding: Style.padding = parse_ui_rect;
+                #[animatable] padding_left: Style.padding.left = parse_val;
+                #[animatable] padding_right: Style.padding.right = parse_val;
+                #[animatable] padding_top: Style.padding.top = parse_val;
+                #[animatable] padding_bottom: Style.padding.bottom = parse_val;
+                #[animatable] border_width: Style.border = parse_ui_rect;
+                #[animatable] border_width_left: Style.border.left = parse_val;
+                #[animatable] border_width_right: Style.border.right = parse_val;
+                #[animatable] border_width_top: Style.border.top = parse_val;
+                #[animatable] border_width_bottom: Style.border.bottom = parse_val;
+                #[animatable] border_color: BorderColor.0 = parse_color;
+                #[animatable] outline_width: Outline.width = parse_val;
+                #[animatable] outline_offset: Outline.offset = parse_val;
+                #[animatable] outline_color: Outline.color = parse_color;
+                #[animatable] border_radius: BorderRadius = parse_border_radius;
+                #[animatable] border_radius_top_left: BorderRadius.top_left = parse_val;
+                #[animatable] border_radius_top_right: BorderRadius.top_right = parse_val;
+                #[animatable] border_radius_bottom_right: BorderRadius.bottom_right = parse_val;
+                #[animatable] border_radius_bottom_left: BorderRadius.bottom_left = parse_val;
+                #[animatable] box_shadow: BoxShadow = parse_box_shadow;
+                #[animatable] box_shadow_color: BoxShadow.color = parse_color;
+                flex_direction: Style.flex_direction = parse_flex_direction;
+                flex_wrap: Style.flex_wrap = parse_flex_wrap;
+                #[animatable] flex_grow: Style.flex_grow = parse_f32;
+                #[animatable] flex_shrink: Style.flex_shrink = parse_f32;
+                #[animatable] flex_basis: Style.flex_basis = parse_val;
+                #[animatable] row_gap: Style.row_gap = parse_val;
+                #[animatable] column_gap: Style.column_gap = parse_val;
+                grid_auto_flow: Style.grid_auto_flow = parse_grid_auto_flow;
+                grid_template_rows: Style.grid_template_rows = parse_repeated_grid_tracks;
+                grid_template_columns: Style.grid_template_columns = parse_repeated_grid_tracks;
+                grid_auto_rows: Style.grid_auto_rows = parse_grid_tracks;
+                grid_auto_columns: Style.grid_auto_columns = parse_grid_tracks;
+                grid_row: Style.grid_row = parse_grid_placement;
+                grid_column: Style.grid_column = parse_grid_placement;
+                #[animatable] background_color: BackgroundColor.0 = parse_color;
+                #[animatable] translation: UiTransform.translation = parse_val_pair;
+                #[animatable] translation_x: UiTransform.translation.0 = parse_val;
+                #[animatable] translation_y: UiTransform.translation.1 = parse_val;
+                #[animatable] rotation: UiTransform.rotation = parse_angle;
+                #[animatable] scale: UiTransform.scale = parse_scale;
+                #[animatable] scale_x: UiTransform.scale.x = parse_f32;
+                #[animatable] scale_y: UiTransform.scale.y = parse_f32;
+                #[animatable] transform_origin: UiTransform.origin = parse_transform_origin;
+                visibility: Visibility = parse_visibility;
+                z_index: ZIndex = parse_z_index;
+            }
+            text {
+                text: TextSection.value = parse_string;
+                text_direction: Style.direction = parse_direction;
+                text_multiline_justification: Text.justify = parse_justify_text;
+                #[animatable] text_size: TextSection.style.font_size = parse_f32;
+                #[animatable] text_color: TextSection.style.color = parse_color;
+            }
+            image {
+                image_asset_path: UiImage => set_image_texture;
+                image_handle: UiImage => set_image_texture;
+                #[animatable] image_tint: BackgroundColor.0 = parse_color;
+                image_flip_x: UiImage.flip_x = parse_bool;
+                image_flip_y: UiImage.flip_y = parse_bool;
+                image_texture_atlas_layout: ImageComponents => set_image_texture_atlas_layout;
+                image_texture_atlas_index: ImageComponents => set_image_texture_atlas_index;
+                image_scale_mode: ImageComponents => set_image_scale_mode;
+                image_slice_border: TextureSlicer.border = parse_border_rect;
+                image_slice_border_left: TextureSlicer.border.left = parse_f32;
+                image_slice_border_right: TextureSlicer.border.right = parse_f32;
+                image_slice_border_top: TextureSlicer.border.top = parse_f32;
+                image_slice_border_bottom: TextureSlicer.border.bottom = parse_f32;
+                image_slice_center_scale_mode: TextureSlicer.center_scale_mode =
+                    parse_slice_scale_mode;
+                image_slice_sides_scale_mode: TextureSlicer.sides_scale_mode =
+                    parse_slice_scale_mode;
+                image_slice_max_corner_scale: TextureSlicer.max_corner_scale = parse_f32;
+            }
+            material_node {
+                material => set_material;
+            }
+        }
+    };
+
+    // Declares the attributes of one element group as `AttributeDescription` constants.
+    (@consts [$element:ident] $(
+        $group:ident {$(
+            $(#[$animatable:ident])?
+            $name:ident $(: $target:ident $(.$field:tt)*)? $(= $parse:ident)? $(=> $setter:ident)?;
+        )*}
+    )*) => {
+        $($($crate::attributes!(@const $element $group $name);)*)*
+    };
+    (@const node node $name:ident) => {
+        pub const $name: AttributeDescription = (stringify!($name), None, false);
+    };
+    (@const text text $name:ident) => {
+        pub const $name: AttributeDescription = (stringify!($name), None, false);
+    };
+    (@const image image $name:ident) => {
+        pub const $name: AttributeDescription = (stringify!($name), None, false);
+    };
+    (@const material_node material_node $name:ident) => {
+        pub const $name: AttributeDescription = (stringify!($name), None, false);
+    };
+    (@const $element:ident $group:ident $name:ident) => {};
+
+    // Generates `map_attribute_name`, which finds the attribute of a built-in element by name.
+    (@map_attribute_name [] $(
+        $group:ident {$(
+            $(#[$animatable:ident])?
+            $name:ident $(: $target:ident $(.$field:tt)*)? $(= $parse:ident)? $(=> $setter:ident)?;
+        )*}
+    )*) => {
+        fn map_attribute_name(tag: &str, name: &str) -> Option<&'static str> {
+            $($(
+                if $crate::attributes!(@applies_to $group tag) && name == stringify!($name) {
+                    return Some(stringify!($name));
+                }
+            )*)*
+            None
+        }
+    };
+    (@applies_to node $tag:ident) => {
+        true
+    };
+    (@applies_to $group:ident $tag:ident) => {
+        $tag == stringify!($group)
+    };
+
+    // Generates `set_attribute`, given `AttributeInput`, `AttributeTargets`, `target_component`,
+    // `text_value` and `update_rounded_box` in scope.
+    (@set_attribute [] $(
+        $group:ident {$(
+            $(#[$animatable:ident])?
+            $name:ident $(: $target:ident $(.$field:tt)*)? $(= $parse:ident)? $(=> $setter:ident)?;
+        )*}
+    )*) => {
+        pub fn set_attribute(
+            name: &str,
+            value: AttributeInput,
+            mut targets: AttributeTargets,
+            asset_server: &AssetServer,
+        ) -> Result<(), String> {
+            let targets = &mut targets;
+            match name {
+                $($(stringify!($name) => {
+                    $crate::attributes!(@check_element $group targets);
+                    $crate::attributes!(
+                        @set targets value asset_server
+                        [$($target $(.$field)*)?] $(= $parse)? $(=> $setter)?
+                    );
+                })*)*
+                _ => return Err("unsupported attribute".to_owned()),
+            }
+            update_rounded_box(name, targets);
+            Ok(())
+        }
+    };
+    (@check_element node $targets:ident) => {};
+    (@check_element material_node $targets:ident) => {};
+    (@check_element $group:ident $targets:ident) => {
+        if $targets.$group.is_none() {
+            return Err(concat!("only supported on `", stringify!($group), "` elements").to_owned());
+        }
+    };
+    (@set $targets:ident $value:ident $asset_server:ident [$target:ident] = $parse:ident) => {
+        *target_component::<$target>($targets)? = $parse(text_value($value)?)?
+    };
+    (@set $targets:ident $value:ident $asset_server:ident
+        [$target:ident $(.$field:tt)+] = $parse:ident) => {
+        target_component::<$target>($targets)?$(.$field)+ = $parse(text_value($value)?)?
+    };
+    (@set $targets:ident $value:ident $asset_server:ident [$target:ident] => $setter:ident) => {
+        $setter(target_component::<$target>($targets)?, $value, $asset_server)?
+    };
+    (@set $targets:ident $value:ident $asset_server:ident [] => $setter:ident) => {
+        $setter($value)?
+    };
+}
+
+/// Declares the attributes shared by every node, for use in custom elements.
+#[macro_export]
 macro_rules! node_attributes {
     () => {
-        pub const animate: AttributeDescription = ("animate", None, false);
-        pub const display: AttributeDescription = ("display", None, false);
-        pub const position: AttributeDescription = ("position", None, false);
-        pub const overflow: AttributeDescription = ("overflow", None, false);
-        pub const overflow_x: AttributeDescription = ("overflow_x", None, false);
-        pub const overflow_y: AttributeDescription = ("overflow_y", None, false);
-        pub const left: AttributeDescription = ("left", None, false);
-        pub const right: AttributeDescription = ("right", None, false);
-        pub const top: AttributeDescription = ("top", None, false);
-        pub const bottom: AttributeDescription = ("bottom", None, false);
-        pub const width: AttributeDescription = ("width", None, false);
-        pub const height: AttributeDescription = ("height", None, false);
-        pub const min_width: AttributeDescription = ("min_width", None, false);
-        pub const min_height: AttributeDescription = ("min_height", None, false);
-        pub const aspect_ratio: AttributeDescription = ("aspect_ratio", None, false);
-        pub const align_items: AttributeDescription = ("align_items", None, false);
-        pub const justify_items: AttributeDescription = ("justify_items", None, false);
-        pub const align_self: AttributeDescription = ("align_self", None, false);
-        pub const justify_self: AttributeDescription = ("justify_self", None, false);
-        pub const align_content: AttributeDescription = ("align_content", None, false);
-        pub const justify_content: AttributeDescription = ("justify_content", None, false);
-        pub const margin: AttributeDescription = ("margin", None, false);
-        pub const margin_left: AttributeDescription = ("margin_left", None, false);
-        pub const margin_right: AttributeDescription = ("margin_right", None, false);
-        pub const margin_top: AttributeDescription = ("margin_top", None, false);
-        pub const margin_bottom: AttributeDescription = ("margin_bottom", None, false);
-        pub const padding: AttributeDescription = ("padding", None, false);
-        pub const padding_left: AttributeDescription = ("padding_left", None, false);
-        pub const padding_right: AttributeDescription = ("padding_right", None, false);
-        pub const padding_top: AttributeDescription = ("padding_top", None, false);
-        pub const padding_bottom: AttributeDescription = ("padding_bottom", None, false);
-        pub const border_width: AttributeDescription = ("border_width", None, false);
-        pub const border_width_left: AttributeDescription = ("border_width_left", None, false);
-        pub const border_width_right: AttributeDescription = ("border_width_right", None, false);
-        pub const border_width_top: AttributeDescription = ("border_width_top", None, false);
-        pub const border_width_bottom: AttributeDescription = ("border_width_bottom", None, false);
-        pub const border_color: AttributeDescription = ("border_color", None, false);
-        pub const outline_width: AttributeDescription = ("outline_width", None, false);
-        pub const outline_offset: AttributeDescription = ("outline_offset", None, false);
-        pub const outline_color: AttributeDescription = ("outline_color", None, false);
-        pub const flex_direction: AttributeDescription = ("flex_direction", None, false);
-        pub const flex_wrap: AttributeDescription = ("flex_wrap", None, false);
-        pub const flex_grow: AttributeDescription = ("flex_grow", None, false);
-        pub const flex_shrink: AttributeDescription = ("flex_shrink", None, false);
-        pub const flex_basis: AttributeDescription = ("flex_basis", None, false);
-        pub const row_gap: AttributeDescription = ("row_gap", None, false);
-        pub const column_gap: AttributeDescription = ("column_gap", None, false);
-        pub const grid_auto_flow: AttributeDescription = ("grid_auto_flow", None, false);
-        pub const grid_template_rows: AttributeDescription = ("grid_template_rows", None, false);
-        pub const grid_template_columns: AttributeDescription =
-            ("grid_template_columns", None, false);
-        pub const grid_auto_rows: AttributeDescription = ("grid_auto_rows", None, false);
-        pub const grid_auto_columns: AttributeDescription = ("grid_auto_columns", None, false);
-        pub const grid_row: AttributeDescription = ("grid_row", None, false);
-        pub const grid_column: AttributeDescription = ("grid_column", None, false);
-        pub const background_color: AttributeDescription = ("background_color", None, false);
-        pub const translation: AttributeDescription = ("translation", None, false);
-        pub const translation_x: AttributeDescription = ("translation", None, false);
-        pub const translation_y: AttributeDescription = ("translation", None, false);
-        pub const rotation: AttributeDescription = ("rotation", None, false);
-        pub const scale: AttributeDescription = ("scale", None, false);
-        pub const scale_x: AttributeDescription = ("scale_x", None, false);
-        pub const scale_y: AttributeDescription = ("scale_y", None, false);
-        pub const visibility: AttributeDescription = ("visibility", None, false);
-        pub const z_index: AttributeDescription = ("z_index", None, false);
+        $crate::attributes!(@consts node);
     };
 }
 
@@ -86,12 +246,7 @@ pub mod dioxus_elements {
     impl text {
         pub const TAG_NAME: &'static str = "text";
         pub const NAME_SPACE: Option<&'static str> = NAME_SPACE;
-        pub const text: AttributeDescription = ("text", None, false);
-        pub const text_direction: AttributeDescription = ("text_direction", None, false);
-        pub const text_multiline_justification: AttributeDescription =
-            ("text_multiline_justification", None, false);
-        pub const text_size: AttributeDescription = ("text_size", None, false);
-        pub const text_color: AttributeDescription = ("text_color", None, false);
+        crate::attributes!(@consts text);
         node_attributes!();
     }
 
@@ -99,7 +254,15 @@ pub mod dioxus_elements {
     impl image {
         pub const TAG_NAME: &'static str = "image";
         pub const NAME_SPACE: Option<&'static str> = NAME_SPACE;
-        pub const image_asset_path: AttributeDescription = ("image_asset_path", None, false);
+        crate::attributes!(@consts image);
+        node_attributes!();
+    }
+
+    pub struct material_node;
+    impl material_node {
+        pub const TAG_NAME: &'static str = "material_node";
+        pub const NAME_SPACE: Option<&'static str> = NAME_SPACE;
+        crate::attributes!(@consts material_node);
         node_attributes!();
     }
 }
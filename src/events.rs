@@ -3,18 +3,25 @@ use bevy::{
         component::Component,
         entity::{Entity, EntityHashSet},
         event::{Event, EventWriter, Events, ManualEventReader},
+        query::{Added, Changed},
         system::{Local, Query, Resource},
         world::World,
     },
     hierarchy::Parent,
+    log::error,
+    math::Vec2,
     prelude::EntityWorldMut,
-    ui::RelativeCursorPosition,
+    transform::components::GlobalTransform,
+    ui::{Node, RelativeCursorPosition},
 };
 use bevy_mod_picking::events::{Click, Down, Out, Over, Pointer, Up};
-use std::{any::Any, mem, rc::Rc};
+use dioxus::prelude::spawn;
+use std::{any::Any, fmt::Display, future::Future, marker::PhantomData, mem, rc::Rc};
 
 // TODO: Other events
 pub mod events {
+    use super::NodeRef;
+    use bevy::math::Vec2;
     use bevy_mod_picking::pointer::PointerButton;
 
     super::impl_event! [
@@ -31,6 +38,16 @@ pub mod events {
         onclick_down
         onclick_up
     ];
+
+    super::impl_event! [
+        NodeRef;
+        onmounted
+    ];
+
+    super::impl_event! [
+        Vec2;
+        onresize
+    ];
 }
 
 #[derive(Resource, Default)]
@@ -42,6 +59,8 @@ pub struct EventReaders {
     mouse_out: ManualEventReader<Pointer<Out>>,
     mouse_enter: ManualEventReader<MouseEnter>,
     mouse_exit: ManualEventReader<MouseExit>,
+    mounted: ManualEventReader<Mounted>,
+    resize: ManualEventReader<Resize>,
 }
 
 impl EventReaders {
@@ -55,6 +74,8 @@ impl EventReaders {
         mouse_out: &Events<Pointer<Out>>,
         mouse_enter: &Events<MouseEnter>,
         mouse_exit: &Events<MouseExit>,
+        mounted: &Events<Mounted>,
+        resize: &Events<Resize>,
     ) -> Vec<(Entity, &'static str, Rc<dyn Any>, bool)> {
         let mut events: Vec<(Entity, &'static str, Rc<dyn Any>, bool)> = Vec::new();
         for event in self.click.read(click) {
@@ -78,11 +99,17 @@ impl EventReaders {
         for event in self.mouse_exit.read(mouse_exit) {
             events.push((event.target, "mouse_exit", Rc::new(()), false));
         }
+        for event in self.mounted.read(mounted) {
+            events.push((event.target, "mounted", Rc::new(event.node_ref), false));
+        }
+        for event in self.resize.read(resize) {
+            events.push((event.target, "resize", Rc::new(event.size), false));
+        }
         events
     }
 }
 
-pub fn insert_event_listener(name: &str, mut entity: EntityWorldMut<'_>) {
+pub fn insert_event_listener(name: &str, mut entity: EntityWorldMut<'_>) -> Result<(), String> {
     match name {
         "click" => entity.insert(HasClickEventListener),
         "click_down" => entity.insert(HasClickDownEventListener),
@@ -96,8 +123,11 @@ pub fn insert_event_listener(name: &str, mut entity: EntityWorldMut<'_>) {
         "mouse_exit" => {
             entity.insert((HasMouseExitEventListener, RelativeCursorPosition::default()))
         }
-        _ => panic!("Encountered unsupported bevy_dioxus event `{name}`."),
+        "mounted" => entity.insert(HasMountedEventListener),
+        "resize" => entity.insert(HasResizeEventListener::default()),
+        _ => return Err("unsupported event".to_owned()),
     };
+    Ok(())
 }
 
 pub fn remove_event_listener(name: &str, mut entity: EntityWorldMut<'_>) {
@@ -121,7 +151,9 @@ pub fn remove_event_listener(name: &str, mut entity: EntityWorldMut<'_>) {
             }
             &mut entity
         }
-        _ => unreachable!(),
+        "mounted" => entity.remove::<HasMountedEventListener>(),
+        "resize" => entity.remove::<HasResizeEventListener>(),
+        _ => &mut entity,
     };
 }
 
@@ -140,6 +172,14 @@ pub struct HasMouseEnterEventListener;
 #[derive(Component)]
 pub struct HasMouseExitEventListener;
 
+#[derive(Component)]
+pub struct HasMountedEventListener;
+
+#[derive(Component, Default)]
+pub struct HasResizeEventListener {
+    previous_size: Vec2,
+}
+
 // ----------------------------------------------------------------------------
 
 pub fn bubble_event(event_name: &str, target_entity: &mut Entity, world: &World) {
@@ -206,12 +246,106 @@ pub struct MouseExit {
 
 // ----------------------------------------------------------------------------
 
+pub fn generate_mounted_events(
+    entities: Query<(Entity, &Node, &GlobalTransform), Added<HasMountedEventListener>>,
+    mut mounted: EventWriter<Mounted>,
+) {
+    mounted.send_batch(
+        entities
+            .iter()
+            .map(|(entity, node, global_transform)| Mounted {
+                target: entity,
+                node_ref: NodeRef {
+                    entity,
+                    size: node.size(),
+                    global_transform: *global_transform,
+                },
+            }),
+    );
+}
+
+#[derive(Event)]
+pub struct Mounted {
+    target: Entity,
+    node_ref: NodeRef,
+}
+
+pub fn generate_resize_events(
+    mut entities: Query<(Entity, &Node, &mut HasResizeEventListener), Changed<Node>>,
+    mut resize: EventWriter<Resize>,
+) {
+    for (entity, node, mut listener) in &mut entities {
+        let size = node.size();
+        if size != listener.previous_size {
+            listener.previous_size = size;
+            resize.send(Resize {
+                target: entity,
+                size,
+            });
+        }
+    }
+}
+
+#[derive(Event)]
+pub struct Resize {
+    target: Entity,
+    size: Vec2,
+}
+
+/// The Bevy entity backing an element, and its computed layout at the time it was mounted.
+#[derive(Clone, Copy, Debug)]
+pub struct NodeRef {
+    pub entity: Entity,
+    pub size: Vec2,
+    /// The node's global transform, whose translation is the center of the node in window
+    /// coordinates.
+    pub global_transform: GlobalTransform,
+}
+
+// ----------------------------------------------------------------------------
+
+/// What an event handler can return: `()`, a `Result`, or a future, which is spawned on the
+/// `VirtualDom`.
 pub trait EventReturn<P>: Sized {
     fn spawn(self) {}
 }
 
 impl EventReturn<()> for () {}
 
+#[doc(hidden)]
+pub struct ResultMarker;
+
+/// Handlers can return errors, which are logged.
+impl<E: Display> EventReturn<ResultMarker> for Result<(), E> {
+    fn spawn(self) {
+        if let Err(error) = self {
+            error!("Event handler failed: {error}");
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct AsyncMarker;
+
+/// Async handlers are spawned as tasks on the `VirtualDom`.
+impl<F: Future<Output = ()> + 'static> EventReturn<AsyncMarker> for F {
+    fn spawn(self) {
+        spawn(self);
+    }
+}
+
+#[doc(hidden)]
+pub struct AsyncResultMarker<E>(PhantomData<E>);
+
+/// Async handlers can return errors too, which are logged once the task finishes.
+impl<E: Display + 'static, F: Future<Output = Result<(), E>> + 'static>
+    EventReturn<AsyncResultMarker<E>> for F
+{
+    fn spawn(self) {
+        spawn(async move { EventReturn::spawn(self.await) });
+    }
+}
+
 macro_rules! impl_event {
     (
         $data:ty;
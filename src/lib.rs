@@ -1,6 +1,11 @@
 mod apply_mutations;
+mod attribute_values;
 pub mod colors;
+mod custom_attributes;
+mod custom_elements;
+mod deferred_commands;
 mod deferred_system;
+mod diagnostics;
 mod ecs_hooks;
 mod elements;
 #[macro_use]
@@ -8,17 +13,32 @@ mod events;
 #[cfg(feature = "hot_reload")]
 mod hot_reload;
 mod parse_attributes;
+mod rounded_boxes;
+mod tasks;
 mod tick;
+mod ui_materials;
+mod ui_transform;
 
 use self::{
     apply_mutations::BevyTemplate,
+    custom_attributes::{CustomAttribute, CustomAttributes},
+    custom_elements::CustomElements,
+    deferred_commands::DeferredCommandQueue,
     deferred_system::DeferredSystemRunQueue,
+    diagnostics::UiDiagnostic,
     ecs_hooks::EcsSubscriptions,
-    events::{generate_mouse_enter_leave_events, EventReaders, MouseEnter, MouseExit},
+    events::{
+        generate_mounted_events, generate_mouse_enter_leave_events, generate_resize_events,
+        EventReaders, Mounted, MouseEnter, MouseExit, Resize,
+    },
+    rounded_boxes::{update_rounded_boxes, RoundedBoxMaterial, ROUNDED_BOX_SHADER_HANDLE},
     tick::tick_dioxus_ui,
+    ui_materials::{UiMaterialType, UiMaterials},
+    ui_transform::apply_ui_transforms,
 };
 use bevy::{
-    app::{App, Last, Plugin, PreUpdate},
+    app::{App, Last, Plugin, PostUpdate, PreUpdate},
+    asset::load_internal_asset,
     ecs::{
         bundle::Bundle,
         component::Component,
@@ -26,28 +46,131 @@ use bevy::{
         schedule::IntoSystemConfigs,
     },
     prelude::Deref,
-    ui::{node_bundles::NodeBundle, ui_focus_system},
+    render::{render_resource::Shader, RenderApp},
+    transform::TransformSystem,
+    ui::{node_bundles::NodeBundle, ui_focus_system, UiMaterial, UiMaterialPlugin, UiSystem},
     utils::HashMap,
 };
 use dioxus::dioxus_core::{Element, ElementId, VirtualDom};
+use std::{cell::RefCell, hash::Hash, rc::Rc};
 
 pub mod prelude {
-    pub use super::deferred_system::use_system_scheduler;
+    pub use super::attribute_values::{AssetHandle, Components};
+    pub use super::custom_elements::CustomElement;
+    pub use super::deferred_commands::{use_commands, use_event_writer};
+    pub use super::deferred_system::{use_system, use_system_scheduler};
+    pub use super::diagnostics::UiDiagnostic;
     pub use super::ecs_hooks::{
+        use_asset,
+        use_load_state,
         use_query,
         use_query_filtered,
         use_resource,
+        use_window_size,
         use_world,
         // use_event_reader, TODO
+        EcsRef,
+        UseAsset,
     };
     pub use super::elements::*;
+    pub use super::events::NodeRef;
+    pub use super::rounded_boxes::{BorderRadius, BoxShadow, RoundedBox};
+    pub use super::tasks::use_task;
     pub use super::{DioxusUiBundle, DioxusUiPlugin, DioxusUiRoot};
     pub use bevy_mod_picking::pointer::PointerButton;
     pub use dioxus;
     pub use dioxus::prelude::{Event as DioxusEvent, *};
 }
 
-pub struct DioxusUiPlugin;
+/// Rounded corners, shadows and `material_node` elements are drawn with UI materials, so they are
+/// only drawn if Bevy's `RenderPlugin` is added before this plugin. `image` and `material_node`
+/// elements can't have rounded corners or shadows yet.
+#[derive(Default)]
+pub struct DioxusUiPlugin {
+    custom_elements: CustomElements,
+    custom_attributes: CustomAttributes,
+    ui_materials: UiMaterials,
+}
+
+impl DioxusUiPlugin {
+    /// Registers an element that can be used in `rsx!` under the given tag.
+    ///
+    /// The tag must also be declared in a `dioxus_elements` module in scope of the `rsx!` call.
+    pub fn with_custom_element(mut self, tag: &'static str, custom_element: CustomElement) -> Self {
+        self.custom_elements.insert(tag, custom_element);
+        self
+    }
+
+    /// Registers an attribute that can be used on every element, which parses its string value
+    /// with `parse` and writes it to the element's `C` component with `set`. Values of type `T`
+    /// can also be passed directly, including numbers and bools when `T` is a primitive type that
+    /// can hold them.
+    ///
+    /// The attribute must also be declared on the elements of a `dioxus_elements` module in scope
+    /// of the `rsx!` call. Panics if it is already registered.
+    ///
+    /// ```ignore
+    /// DioxusUiPlugin::default().with_custom_attribute(
+    ///     "glow_intensity",
+    ///     |value| value.parse::<f32>().map_err(|error| error.to_string()),
+    ///     |glow: &mut Glow, intensity| glow.intensity = intensity,
+    /// )
+    /// ```
+    pub fn with_custom_attribute<C: Component + Default, T: Clone + 'static>(
+        mut self,
+        name: &'static str,
+        parse: fn(&str) -> Result<T, String>,
+        set: fn(&mut C, T),
+    ) -> Self {
+        self.custom_attributes
+            .insert(name, CustomAttribute::new(parse, set));
+        self
+    }
+
+    /// Registers a [`UiMaterial`] that `material_node` elements can select with their `material`
+    /// attribute. Each element gets its own copy of `M::default()`.
+    ///
+    /// ```ignore
+    /// rsx! {
+    ///     material_node { material: "health_bar", width: "200px", height: "20px" }
+    /// }
+    /// ```
+    pub fn with_ui_material<M: UiMaterial + Default>(mut self, name: &'static str) -> Self
+    where
+        M::Data: PartialEq + Eq + Hash + Clone,
+    {
+        self.ui_materials.insert(name, UiMaterialType::new::<M>());
+        self
+    }
+
+    /// Registers an attribute for `material_node` elements using an `M` material, which parses its
+    /// string value with `parse` and writes it to the element's material asset with `set`. Values
+    /// of type `T` can also be passed directly, as with
+    /// [`with_custom_attribute`](Self::with_custom_attribute).
+    ///
+    /// Other materials can register attributes with the same name. Panics if it is already
+    /// registered for `M`.
+    ///
+    /// ```ignore
+    /// DioxusUiPlugin::default()
+    ///     .with_ui_material::<HealthBarMaterial>("health_bar")
+    ///     .with_material_attribute(
+    ///         "health",
+    ///         |value| value.parse::<f32>().map_err(|error| error.to_string()),
+    ///         |material: &mut HealthBarMaterial, health| material.health = health,
+    ///     )
+    /// ```
+    pub fn with_material_attribute<M: UiMaterial, T: Clone + 'static>(
+        mut self,
+        name: &'static str,
+        parse: fn(&str) -> Result<T, String>,
+        set: fn(&mut M, T),
+    ) -> Self {
+        self.custom_attributes
+            .insert(name, CustomAttribute::for_material(parse, set));
+        self
+    }
+}
 
 impl Plugin for DioxusUiPlugin {
     fn build(&self, app: &mut App) {
@@ -56,15 +179,51 @@ impl Plugin for DioxusUiPlugin {
             hot_reload::HotReloadContext,
         >::default());
 
+        // Materials can only be drawn if the render plugin was added first, which headless apps
+        // don't add at all
+        if app.get_sub_app(RenderApp).is_ok() {
+            load_internal_asset!(
+                app,
+                ROUNDED_BOX_SHADER_HANDLE,
+                "rounded_box.wgsl",
+                Shader::from_wgsl
+            );
+            app.add_plugins(UiMaterialPlugin::<RoundedBoxMaterial>::default())
+                .add_systems(
+                    PostUpdate,
+                    update_rounded_boxes.after(TransformSystem::TransformPropagate),
+                );
+            for ui_material in self.ui_materials.values() {
+                (ui_material.add_plugin)(app);
+            }
+        }
+
         app.init_non_send_resource::<UiContext>()
             .init_resource::<DeferredSystemRunQueue>()
+            .init_resource::<DeferredCommandQueue>()
+            .insert_resource(self.custom_elements.clone())
+            .insert_resource(self.custom_attributes.clone())
+            .insert_resource(self.ui_materials.clone())
             .init_resource::<EventReaders>()
+            .add_event::<UiDiagnostic>()
             .add_event::<MouseEnter>()
             .add_event::<MouseExit>()
+            .add_event::<Mounted>()
+            .add_event::<Resize>()
             .add_systems(
                 PreUpdate,
                 generate_mouse_enter_leave_events.after(ui_focus_system),
             )
+            .add_systems(
+                PostUpdate,
+                (
+                    generate_mounted_events.after(TransformSystem::TransformPropagate),
+                    generate_resize_events.after(UiSystem::Layout),
+                    apply_ui_transforms
+                        .after(UiSystem::Layout)
+                        .before(TransformSystem::TransformPropagate),
+                ),
+            )
             .add_systems(Last, tick_dioxus_ui);
     }
 }
@@ -81,7 +240,7 @@ pub struct DioxusUiRoot(pub fn() -> Element);
 #[derive(Default)]
 struct UiContext {
     roots: HashMap<(Entity, DioxusUiRoot), UiRoot>,
-    subscriptions: EcsSubscriptions,
+    subscriptions: Rc<RefCell<EcsSubscriptions>>,
 }
 
 struct UiRoot {
@@ -1,247 +1,1320 @@
+use crate::{
+    apply_mutations::ImageComponents,
+    attribute_values::AssetHandle,
+    colors,
+    rounded_boxes::{BorderRadius, BoxShadow, RoundedBox},
+    ui_transform::UiTransform,
+};
 use bevy::{
-    asset::{AssetPath, AssetServer},
-    math::Quat,
+    asset::{Asset, AssetPath, AssetServer, Handle},
+    math::Vec2,
     render::{color::Color, view::Visibility},
-    text::{JustifyText, Text},
-    transform::components::Transform,
+    sprite::{BorderRect, ImageScaleMode, SliceScaleMode, TextureAtlas, TextureSlicer},
+    text::{JustifyText, Text, TextSection},
     ui::*,
 };
-use std::f32::consts::PI;
-
-#[allow(clippy::too_many_arguments)]
-pub fn set_attribute(
-    name: &str,
-    value: &str,
-    style: &mut Style,
-    border_color: &mut BorderColor,
-    outline: &mut Outline,
-    background_color: &mut BackgroundColor,
-    transform: &mut Transform,
-    visibility: &mut Visibility,
-    z_index: &mut ZIndex,
-    text: Option<&mut Text>,
-    image: Option<&mut UiImage>,
-    asset_server: &AssetServer,
-) {
-    #[allow(unused_variables, unreachable_code)]
-    match (name, value) {
-        ("animate", value) => todo!(),
-        ("display", "flex") => style.display = Display::Flex,
-        ("display", "grid") => style.display = Display::Grid,
-        ("display", "none") => style.display = Display::None,
-        ("position", "relative") => style.position_type = PositionType::Relative,
-        ("position", "absolute") => style.position_type = PositionType::Absolute,
-        ("overflow", "visible") => style.overflow = Overflow::visible(),
-        ("overflow", "clip") => style.overflow = Overflow::clip(),
-        ("overflow_x", "visible") => style.overflow.x = OverflowAxis::Visible,
-        ("overflow_x", "clip") => style.overflow.x = OverflowAxis::Clip,
-        ("overflow_y", "visible") => style.overflow.y = OverflowAxis::Visible,
-        ("overflow_y", "clip") => style.overflow.y = OverflowAxis::Clip,
-        ("left", value) => style.left = parse_val(value),
-        ("right", value) => style.right = parse_val(value),
-        ("top", value) => style.top = parse_val(value),
-        ("bottom", value) => style.bottom = parse_val(value),
-        ("width", value) => style.width = parse_val(value),
-        ("height", value) => style.height = parse_val(value),
-        ("min_width", value) => style.min_width = parse_val(value),
-        ("min_height", value) => style.min_height = parse_val(value),
-        ("max_width", value) => style.max_width = parse_val(value),
-        ("max_height", value) => style.max_height = parse_val(value),
-        ("aspect_ratio", "none") => style.aspect_ratio = None,
-        ("aspect_ratio", value) => style.aspect_ratio = Some(parse_f32(value)),
-        ("align_items", "default") => style.align_items = AlignItems::Default,
-        ("align_items", "start") => style.align_items = AlignItems::Start,
-        ("align_items", "end") => style.align_items = AlignItems::End,
-        ("align_items", "flex_start") => style.align_items = AlignItems::FlexStart,
-        ("align_items", "flex_end") => style.align_items = AlignItems::FlexEnd,
-        ("align_items", "center") => style.align_items = AlignItems::Center,
-        ("align_items", "baseline") => style.align_items = AlignItems::Baseline,
-        ("align_items", "stretch") => style.align_items = AlignItems::Stretch,
-        ("justify_items", "default") => style.justify_items = JustifyItems::Default,
-        ("justify_items", "start") => style.justify_items = JustifyItems::Start,
-        ("justify_items", "end") => style.justify_items = JustifyItems::End,
-        ("justify_items", "center") => style.justify_items = JustifyItems::Center,
-        ("justify_items", "baseline") => style.justify_items = JustifyItems::Baseline,
-        ("justify_items", "stretch") => style.justify_items = JustifyItems::Stretch,
-        ("align_self", "auto") => style.align_self = AlignSelf::Auto,
-        ("align_self", "start") => style.align_self = AlignSelf::Start,
-        ("align_self", "end") => style.align_self = AlignSelf::End,
-        ("align_self", "flex_start") => style.align_self = AlignSelf::FlexStart,
-        ("align_self", "flex_end") => style.align_self = AlignSelf::FlexEnd,
-        ("align_self", "center") => style.align_self = AlignSelf::Center,
-        ("align_self", "baseline") => style.align_self = AlignSelf::Baseline,
-        ("align_self", "stretch") => style.align_self = AlignSelf::Stretch,
-        ("justify_self", "auto") => style.justify_self = JustifySelf::Auto,
-        ("justify_self", "start") => style.justify_self = JustifySelf::Start,
-        ("justify_self", "end") => style.justify_self = JustifySelf::End,
-        ("justify_self", "center") => style.justify_self = JustifySelf::Center,
-        ("justify_self", "baseline") => style.justify_self = JustifySelf::Baseline,
-        ("justify_self", "stretch") => style.justify_self = JustifySelf::Stretch,
-        ("align_content", "default") => style.align_content = AlignContent::Default,
-        ("align_content", "start") => style.align_content = AlignContent::Start,
-        ("align_content", "end") => style.align_content = AlignContent::End,
-        ("align_content", "flex_start") => style.align_content = AlignContent::FlexStart,
-        ("align_content", "flex_end") => style.align_content = AlignContent::FlexEnd,
-        ("align_content", "center") => style.align_content = AlignContent::Center,
-        ("align_content", "stretch") => style.align_content = AlignContent::Stretch,
-        ("align_content", "space_between") => style.align_content = AlignContent::SpaceBetween,
-        ("align_content", "space_evenly") => style.align_content = AlignContent::SpaceEvenly,
-        ("align_content", "space_around") => style.align_content = AlignContent::SpaceAround,
-        ("justify_content", "default") => style.justify_content = JustifyContent::Default,
-        ("justify_content", "start") => style.justify_content = JustifyContent::Start,
-        ("justify_content", "end") => style.justify_content = JustifyContent::End,
-        ("justify_content", "flex_start") => style.justify_content = JustifyContent::FlexStart,
-        ("justify_content", "flex_end") => style.justify_content = JustifyContent::FlexEnd,
-        ("justify_content", "center") => style.justify_content = JustifyContent::Center,
-        ("justify_content", "stretch") => style.justify_content = JustifyContent::Stretch,
-        ("justify_content", "space_between") => {
-            style.justify_content = JustifyContent::SpaceBetween;
-        }
-        ("justify_content", "space_evenly") => style.justify_content = JustifyContent::SpaceEvenly,
-        ("justify_content", "space_around") => style.justify_content = JustifyContent::SpaceAround,
-        ("margin", value) => style.margin = UiRect::all(parse_val(value)),
-        ("margin_left", value) => style.margin.left = parse_val(value),
-        ("margin_right", value) => style.margin.right = parse_val(value),
-        ("margin_top", value) => style.margin.top = parse_val(value),
-        ("margin_bottom", value) => style.margin.bottom = parse_val(value),
-        ("padding", value) => style.padding = UiRect::all(parse_val(value)),
-        ("padding_left", value) => style.padding.left = parse_val(value),
-        ("padding_right", value) => style.padding.right = parse_val(value),
-        ("padding_top", value) => style.padding.top = parse_val(value),
-        ("padding_bottom", value) => style.padding.bottom = parse_val(value),
-        ("border_width", value) => style.border = UiRect::all(parse_val(value)),
-        ("border_width_left", value) => style.border.left = parse_val(value),
-        ("border_width_right", value) => style.border.right = parse_val(value),
-        ("border_width_top", value) => style.border.top = parse_val(value),
-        ("border_width_bottom", value) => style.border.bottom = parse_val(value),
-        ("border_color", value) => border_color.0 = parse_color(value),
-        ("outline_width", value) => outline.width = parse_val(value),
-        ("outline_offset", value) => outline.offset = parse_val(value),
-        ("outline_color", value) => outline.color = parse_color(value),
-        ("flex_direction", "row") => style.flex_direction = FlexDirection::Row,
-        ("flex_direction", "column") => style.flex_direction = FlexDirection::Column,
-        ("flex_direction", "row_reverse") => style.flex_direction = FlexDirection::RowReverse,
-        ("flex_direction", "column_reverse") => style.flex_direction = FlexDirection::ColumnReverse,
-        ("flex_wrap", "no_wrap") => style.flex_wrap = FlexWrap::NoWrap,
-        ("flex_wrap", "wrap") => style.flex_wrap = FlexWrap::Wrap,
-        ("flex_wrap", "wrap_reverse") => style.flex_wrap = FlexWrap::WrapReverse,
-        ("flex_grow", value) => style.flex_grow = parse_f32(value),
-        ("flex_shrink", value) => style.flex_shrink = parse_f32(value),
-        ("flex_basis", value) => style.flex_basis = parse_val(value),
-        ("row_gap", value) => style.row_gap = parse_val(value),
-        ("column_gap", value) => style.column_gap = parse_val(value),
-        ("grid_auto_flow", "row") => style.grid_auto_flow = GridAutoFlow::Row,
-        ("grid_auto_flow", "column") => style.grid_auto_flow = GridAutoFlow::Column,
-        ("grid_auto_flow", "row_dense") => style.grid_auto_flow = GridAutoFlow::RowDense,
-        ("grid_auto_flow", "column_dense") => style.grid_auto_flow = GridAutoFlow::ColumnDense,
-        ("grid_template_rows", value) => {
-            style.grid_template_rows = todo!();
-        }
-        ("grid_template_columns", value) => {
-            style.grid_template_columns = todo!();
-        }
-        ("grid_auto_rows", value) => {
-            style.grid_auto_rows = todo!();
-        }
-        ("grid_auto_columns", value) => {
-            style.grid_auto_columns = todo!();
-        }
-        ("grid_row", value) => {
-            style.grid_row = todo!();
-        }
-        ("grid_column", value) => {
-            style.grid_column = todo!();
-        }
-        ("background_color", value) => background_color.0 = parse_color(value),
-        ("translation", value) => {
-            let value = parse_f32(value);
-            transform.translation.x = value;
-            transform.translation.y = value;
-        }
-        ("translation_x", value) => transform.translation.x = parse_f32(value),
-        ("translation_y", value) => transform.translation.y = parse_f32(value),
-        ("rotation", value) => {
-            transform.rotation = Quat::from_rotation_y(parse_f32(value) * (180.0 / PI));
-        }
-        ("scale", value) => {
-            let value = parse_f32(value);
-            transform.scale.x = value;
-            transform.scale.y = value;
-        }
-        ("scale_x", value) => transform.scale.x = parse_f32(value),
-        ("scale_y", value) => transform.scale.y = parse_f32(value),
-        ("visibility", "inherited") => *visibility = Visibility::Inherited,
-        ("visibility", "hidden") => *visibility = Visibility::Hidden,
-        ("visibility", "visible") => *visibility = Visibility::Visible,
-        ("z_index", value) => match value.split_once(':') {
-            Some(("local", value)) => *z_index = ZIndex::Local(parse_i32(value)),
-            Some(("global", value)) => *z_index = ZIndex::Global(parse_i32(value)),
-            None => *z_index = ZIndex::Local(parse_i32(value)),
-            _ => panic!("Encountered invalid bevy_dioxus ZIndex `{value}`."),
-        },
-        ("text", value) if text.is_some() => text.unwrap().sections[0].value = value.to_owned(),
-        ("text_direction", "inherit") if text.is_some() => style.direction = Direction::Inherit,
-        ("text_direction", "left_to_right") if text.is_some() => {
-            style.direction = Direction::LeftToRight;
-        }
-        ("text_direction", "right_to_left") if text.is_some() => {
-            style.direction = Direction::RightToLeft;
-        }
-        ("text_multiline_justification", "left") if text.is_some() => {
-            text.unwrap().justify = JustifyText::Left;
-        }
-        ("text_multiline_justification", "center") if text.is_some() => {
-            text.unwrap().justify = JustifyText::Center;
+use std::{any::Any, f32::consts::TAU};
+
+crate::attributes!(@set_attribute);
+
+/// A value given to an attribute, either a string from `rsx!`, a number or bool, or any value from
+/// `IntoAttributeValue`.
+#[derive(Clone, Copy)]
+pub enum AttributeInput<'a> {
+    Text(&'a str),
+    Float(f64),
+    Int(i64),
+    Bool(bool),
+    Any(&'a dyn Any),
+}
+
+/// The components of an element that attributes can write to.
+pub struct AttributeTargets<'a> {
+    pub style: &'a mut Style,
+    pub border_color: &'a mut BorderColor,
+    pub outline: &'a mut Outline,
+    pub border_radius: &'a mut BorderRadius,
+    pub box_shadow: &'a mut BoxShadow,
+    /// `None` for elements that don't support `border_radius` and `box_shadow`.
+    pub rounded_box: Option<&'a mut RoundedBox>,
+    /// `None` for `material_node` elements, which Bevy only draws without a `BackgroundColor`.
+    pub background_color: Option<&'a mut BackgroundColor>,
+    pub transform: &'a mut UiTransform,
+    pub visibility: &'a mut Visibility,
+    pub z_index: &'a mut ZIndex,
+    pub text: Option<&'a mut Text>,
+    pub image: Option<&'a mut ImageComponents>,
+}
+
+trait AttributeTarget {
+    fn get<'a>(targets: &'a mut AttributeTargets) -> Option<&'a mut Self>;
+}
+
+macro_rules! impl_attribute_target {
+    ($($target:ty => |$targets:ident| $get:expr;)*) => {$(
+        impl AttributeTarget for $target {
+            fn get<'a>($targets: &'a mut AttributeTargets) -> Option<&'a mut Self> {
+                $get
+            }
         }
-        ("text_multiline_justification", "right") if text.is_some() => {
-            text.unwrap().justify = JustifyText::Right;
+    )*};
+}
+
+impl_attribute_target! {
+    Style => |targets| Some(&mut *targets.style);
+    BorderColor => |targets| Some(&mut *targets.border_color);
+    Outline => |targets| Some(&mut *targets.outline);
+    BorderRadius => |targets| targets.rounded_box.is_some().then_some(&mut *targets.border_radius);
+    BoxShadow => |targets| targets.rounded_box.is_some().then_some(&mut *targets.box_shadow);
+    BackgroundColor => |targets| targets.background_color.as_deref_mut();
+    UiTransform => |targets| Some(&mut *targets.transform);
+    Visibility => |targets| Some(&mut *targets.visibility);
+    ZIndex => |targets| Some(&mut *targets.z_index);
+    Text => |targets| targets.text.as_deref_mut();
+    TextSection => |targets| targets.text.as_deref_mut().map(|text| &mut text.sections[0]);
+    ImageComponents => |targets| targets.image.as_deref_mut();
+    UiImage => |targets| targets.image.as_deref_mut().map(|image| &mut image.image);
+    TextureSlicer => |targets| {
+        let image = targets.image.as_deref_mut()?;
+        Some(texture_slicer(&mut image.scale_mode))
+    };
+}
+
+/// Keeps the colors of elements with rounded corners or a shadow in their `RoundedBox`.
+fn update_rounded_box(name: &str, targets: &mut AttributeTargets) {
+    if let (Some(rounded_box), Some(background_color)) = (
+        targets.rounded_box.as_deref_mut(),
+        targets.background_color.as_deref_mut(),
+    ) {
+        rounded_box.update(
+            name,
+            targets.border_radius,
+            targets.box_shadow,
+            background_color,
+            targets.border_color,
+        );
+    }
+}
+
+fn target_component<'a, T: AttributeTarget>(
+    targets: &'a mut AttributeTargets,
+) -> Result<&'a mut T, String> {
+    T::get(targets).ok_or_else(|| "unsupported attribute for this element".to_owned())
+}
+
+fn text_value(value: AttributeInput<'_>) -> Result<&str, String> {
+    match value {
+        AttributeInput::Text(value) => Ok(value),
+        _ => Err("expected a string value".to_owned()),
+    }
+}
+
+/// `Components` values are inserted before reaching `set_attribute`.
+fn set_components(_value: AttributeInput) -> Result<(), String> {
+    Err("expected a `Components` value".to_owned())
+}
+
+fn set_material(_value: AttributeInput) -> Result<(), String> {
+    Err("only supported as a literal string on `material_node` elements".to_owned())
+}
+
+fn set_image_texture(
+    image: &mut UiImage,
+    value: AttributeInput,
+    asset_server: &AssetServer,
+) -> Result<(), String> {
+    image.texture = match value {
+        AttributeInput::Text(path) => asset_server.load(AssetPath::parse(path)),
+        AttributeInput::Any(handle) => downcast_handle(handle)?,
+        _ => return Err("expected a path or an `AssetHandle<Image>`".to_owned()),
+    };
+    Ok(())
+}
+
+fn set_image_texture_atlas_layout(
+    image: &mut ImageComponents,
+    value: AttributeInput,
+    _asset_server: &AssetServer,
+) -> Result<(), String> {
+    let layout = match value {
+        AttributeInput::Text("none") => {
+            image.pending_texture_atlas_index = image
+                .texture_atlas
+                .take()
+                .map(|texture_atlas| texture_atlas.index);
+            return Ok(());
         }
-        ("text_size", value) if text.is_some() => {
-            text.unwrap().sections[0].style.font_size = parse_f32(value);
+        AttributeInput::Any(handle) => downcast_handle(handle)?,
+        _ => {
+            return Err(
+                "expected `none` or an `AssetHandle<TextureAtlasLayout>`, since layouts can't be \
+                 loaded from files"
+                    .to_owned(),
+            );
         }
-        ("text_color", value) if text.is_some() => {
-            text.unwrap().sections[0].style.color = parse_color(value);
+    };
+    match &mut image.texture_atlas {
+        Some(texture_atlas) => texture_atlas.layout = layout,
+        None => {
+            let index = image.pending_texture_atlas_index.take().unwrap_or(0);
+            image.texture_atlas = Some(TextureAtlas { layout, index });
         }
-        ("image_asset_path", value) if image.is_some() => {
-            image.unwrap().texture = asset_server.load(AssetPath::parse(value));
+    }
+    Ok(())
+}
+
+fn set_image_texture_atlas_index(
+    image: &mut ImageComponents,
+    value: AttributeInput,
+    _asset_server: &AssetServer,
+) -> Result<(), String> {
+    let index = parse_usize(text_value(value)?)?;
+    // Static attributes are set before dynamic ones, so the layout handle often comes later
+    match &mut image.texture_atlas {
+        Some(texture_atlas) => texture_atlas.index = index,
+        None => image.pending_texture_atlas_index = Some(index),
+    }
+    Ok(())
+}
+
+fn set_image_scale_mode(
+    image: &mut ImageComponents,
+    value: AttributeInput,
+    _asset_server: &AssetServer,
+) -> Result<(), String> {
+    let tiled = |tile_x, tile_y| {
+        Some(ImageScaleMode::Tiled {
+            tile_x,
+            tile_y,
+            stretch_value: 1.0,
+        })
+    };
+    match text_value(value)? {
+        "stretch" => image.scale_mode = None,
+        "sliced" => {
+            texture_slicer(&mut image.scale_mode);
         }
-        _ => panic!("Encountered unsupported bevy_dioxus attribute `{name}: {value}`."),
+        "tiled" => image.scale_mode = tiled(true, true),
+        "tiled_x" => image.scale_mode = tiled(true, false),
+        "tiled_y" => image.scale_mode = tiled(false, true),
+        mode => return Err(format!("invalid ImageScaleMode `{mode}`")),
+    }
+    Ok(())
+}
+
+fn downcast_handle<A: Asset>(value: &dyn Any) -> Result<Handle<A>, String> {
+    match value.downcast_ref::<AssetHandle<A>>() {
+        Some(AssetHandle(handle)) => Ok(handle.clone()),
+        None => Err(format!(
+            "expected `AssetHandle<{}>`",
+            std::any::type_name::<A>()
+        )),
+    }
+}
+
+fn parse_string(string: &str) -> Result<String, String> {
+    Ok(string.to_owned())
+}
+
+fn parse_visibility(visibility: &str) -> Result<Visibility, String> {
+    match visibility {
+        "inherited" => Ok(Visibility::Inherited),
+        "hidden" => Ok(Visibility::Hidden),
+        "visible" => Ok(Visibility::Visible),
+        _ => Err(format!("invalid Visibility `{visibility}`")),
+    }
+}
+
+/// Parses `"local:N"`, `"global:N"`, or `"N"` as a local index.
+fn parse_z_index(z_index: &str) -> Result<ZIndex, String> {
+    match z_index.split_once(':') {
+        Some(("local", value)) => Ok(ZIndex::Local(parse_i32(value)?)),
+        Some(("global", value)) => Ok(ZIndex::Global(parse_i32(value)?)),
+        None => Ok(ZIndex::Local(parse_i32(z_index)?)),
+        _ => Err(format!("invalid ZIndex `{z_index}`")),
+    }
+}
+
+fn parse_justify_text(justify: &str) -> Result<JustifyText, String> {
+    match justify {
+        "left" => Ok(JustifyText::Left),
+        "center" => Ok(JustifyText::Center),
+        "right" => Ok(JustifyText::Right),
+        _ => Err(format!("invalid JustifyText `{justify}`")),
+    }
+}
+
+/// Parses one factor for both axes, or two space-separated factors for x and y.
+fn parse_scale(scale: &str) -> Result<Vec2, String> {
+    match scale.split_once(char::is_whitespace) {
+        Some((x, y)) => Ok(Vec2::new(parse_f32(x.trim())?, parse_f32(y.trim())?)),
+        None => Ok(Vec2::splat(parse_f32(scale)?)),
     }
 }
 
-fn parse_color(hex: &str) -> Color {
-    Color::hex(hex).unwrap_or_else(|_| panic!("Encountered invalid bevy_dioxus Color hex `{hex}`."))
+fn parse_border_rect(border: &str) -> Result<BorderRect, String> {
+    Ok(BorderRect::square(parse_f32(border)?))
 }
 
-fn parse_f32(float: &str) -> f32 {
+/// Parses hex, `rgb()`, `rgba()`, `hsl()`, `hsla()`, CSS named and Tailwind colors, optionally
+/// followed by a Tailwind-style opacity modifier, e.g. `"violet-500/50"`.
+fn parse_color(color: &str) -> Result<Color, String> {
+    if let Some((color, opacity)) = color.rsplit_once('/').filter(|_| !color.ends_with(')')) {
+        return Ok(parse_color(color)?.with_a(parse_f32(opacity)? / 100.0));
+    }
+    if let Some((function, arguments)) = color.strip_suffix(')').and_then(|c| c.split_once('(')) {
+        let arguments = arguments
+            .split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+            .filter(|argument| !argument.is_empty())
+            .collect::<Vec<_>>();
+        return match (function.trim(), &arguments[..]) {
+            ("rgb" | "rgba", [r, g, b]) => Ok(Color::rgb(
+                parse_rgb_channel(r)?,
+                parse_rgb_channel(g)?,
+                parse_rgb_channel(b)?,
+            )),
+            ("rgb" | "rgba", [r, g, b, a]) => Ok(Color::rgba(
+                parse_rgb_channel(r)?,
+                parse_rgb_channel(g)?,
+                parse_rgb_channel(b)?,
+                parse_alpha(a)?,
+            )),
+            ("hsl" | "hsla", [h, s, l]) => Ok(Color::hsl(
+                parse_hue(h)?,
+                parse_percentage(s)?,
+                parse_percentage(l)?,
+            )),
+            ("hsl" | "hsla", [h, s, l, a]) => Ok(Color::hsla(
+                parse_hue(h)?,
+                parse_percentage(s)?,
+                parse_percentage(l)?,
+                parse_alpha(a)?,
+            )),
+            _ => Err(format!("invalid Color `{color}`")),
+        };
+    }
+    let hex = colors::css(color)
+        .or_else(|| colors::tailwind(color))
+        .unwrap_or(color);
+    Color::hex(hex).map_err(|_| format!("invalid Color `{color}`"))
+}
+
+fn parse_rgb_channel(channel: &str) -> Result<f32, String> {
+    match channel.strip_suffix('%') {
+        Some(channel) => Ok(parse_f32(channel)? / 100.0),
+        None => Ok(parse_f32(channel)? / 255.0),
+    }
+}
+
+fn parse_alpha(alpha: &str) -> Result<f32, String> {
+    match alpha.strip_suffix('%') {
+        Some(alpha) => Ok(parse_f32(alpha)? / 100.0),
+        None => parse_f32(alpha),
+    }
+}
+
+fn parse_hue(hue: &str) -> Result<f32, String> {
+    Ok(parse_f32(hue.strip_suffix("deg").unwrap_or(hue))?.rem_euclid(360.0))
+}
+
+fn parse_percentage(percentage: &str) -> Result<f32, String> {
+    Ok(parse_f32(percentage.strip_suffix('%').unwrap_or(percentage))? / 100.0)
+}
+
+fn parse_bool(boolean: &str) -> Result<bool, String> {
+    boolean
+        .parse::<bool>()
+        .map_err(|_| format!("invalid bool `{boolean}`"))
+}
+
+fn parse_f32(float: &str) -> Result<f32, String> {
     float
         .parse::<f32>()
-        .unwrap_or_else(|val| panic!("Encountered invalid bevy_dioxus f32 `{val}`."))
+        .map_err(|_| format!("invalid f32 `{float}`"))
 }
 
-fn parse_i32(int: &str) -> i32 {
+fn parse_i32(int: &str) -> Result<i32, String> {
     int.parse::<i32>()
-        .unwrap_or_else(|val| panic!("Encountered invalid bevy_dioxus i32 `{val}`."))
+        .map_err(|_| format!("invalid i32 `{int}`"))
+}
+
+fn parse_usize(int: &str) -> Result<usize, String> {
+    int.parse::<usize>()
+        .map_err(|_| format!("invalid usize `{int}`"))
 }
 
-fn parse_val(val: &str) -> Val {
+fn parse_val(val: &str) -> Result<Val, String> {
+    if val == "auto" {
+        return Ok(Val::Auto);
+    }
     if let Ok(val) = val.parse::<f32>() {
-        return Val::Px(val);
+        return Ok(Val::Px(val));
+    }
+    let units: [(&str, fn(f32) -> Val); 6] = [
+        ("px", Val::Px),
+        ("%", Val::Percent),
+        ("vw", Val::Vw),
+        ("vh", Val::Vh),
+        ("vmin", Val::VMin),
+        ("vmax", Val::VMax),
+    ];
+    for (unit, to_val) in units {
+        if let Some(val) = val.strip_suffix(unit) {
+            if let Ok(val) = val.parse::<f32>() {
+                return Ok(to_val(val));
+            }
+        }
+    }
+    Err(format!("invalid Val `{val}`"))
+}
+
+/// Parses one value for both axes, or two space-separated values for x and y.
+fn parse_val_pair(pair: &str) -> Result<(Val, Val), String> {
+    match pair.split_once(char::is_whitespace) {
+        Some((x, y)) => Ok((parse_val(x.trim())?, parse_val(y.trim())?)),
+        None => {
+            let val = parse_val(pair)?;
+            Ok((val, val))
+        }
+    }
+}
+
+/// Parses CSS-style origins, e.g. `"center"`, `"left top"` or `"25% 10px"`.
+fn parse_transform_origin(origin: &str) -> Result<(Val, Val), String> {
+    let keyword = |keyword: &str| match keyword {
+        "left" | "top" => Some(Val::Percent(0.0)),
+        "center" => Some(Val::Percent(50.0)),
+        "right" | "bottom" => Some(Val::Percent(100.0)),
+        _ => None,
+    };
+    match origin.split_once(char::is_whitespace) {
+        Some((x, y)) => {
+            let (x, y) = (x.trim(), y.trim());
+            // Allow vertical keywords first, e.g. `"top left"`
+            let (x, y) = match (x, y) {
+                ("top" | "bottom", "left" | "right" | "center") => (y, x),
+                _ => (x, y),
+            };
+            Ok((
+                keyword(x).map_or_else(|| parse_val(x), Ok)?,
+                keyword(y).map_or_else(|| parse_val(y), Ok)?,
+            ))
+        }
+        None => match origin {
+            "top" | "bottom" => Ok((Val::Percent(50.0), keyword(origin).unwrap())),
+            _ => {
+                let x = keyword(origin).map_or_else(|| parse_val(origin), Ok)?;
+                Ok((x, Val::Percent(50.0)))
+            }
+        },
+    }
+}
+
+/// Parses a clockwise angle into radians, in degrees by default, or with a `deg`, `rad` or
+/// `turn` unit.
+fn parse_angle(angle: &str) -> Result<f32, String> {
+    if let Some(angle) = angle.strip_suffix("deg") {
+        Ok(parse_f32(angle)?.to_radians())
+    } else if let Some(angle) = angle.strip_suffix("rad") {
+        parse_f32(angle)
+    } else if let Some(angle) = angle.strip_suffix("turn") {
+        Ok(parse_f32(angle)? * TAU)
+    } else {
+        Ok(parse_f32(angle)?.to_radians())
+    }
+}
+
+/// Parses CSS-style shorthands, e.g. `"4"`, `"4 8"`, `"4 8 2"` or `"4 8 2 0"`
+/// (top, right, bottom, left).
+fn parse_ui_rect(rect: &str) -> Result<UiRect, String> {
+    let vals = rect
+        .split_whitespace()
+        .map(parse_val)
+        .collect::<Result<Vec<_>, _>>()?;
+    match vals[..] {
+        [all] => Ok(UiRect::all(all)),
+        [vertical, horizontal] => Ok(UiRect::new(horizontal, horizontal, vertical, vertical)),
+        [top, horizontal, bottom] => Ok(UiRect::new(horizontal, horizontal, top, bottom)),
+        [top, right, bottom, left] => Ok(UiRect::new(left, right, top, bottom)),
+        _ => Err(format!("invalid UiRect `{rect}`")),
     }
-    if let Some((val, "")) = val.split_once("px") {
-        if let Ok(val) = val.parse::<f32>() {
-            return Val::Px(val);
+}
+
+/// Parses CSS-style radii, from one value for every corner up to four values for the top left, top
+/// right, bottom right and bottom left corners.
+fn parse_border_radius(radius: &str) -> Result<BorderRadius, String> {
+    let vals = radius
+        .split_whitespace()
+        .map(parse_val)
+        .collect::<Result<Vec<_>, _>>()?;
+    let (top_left, top_right, bottom_right, bottom_left) = match vals[..] {
+        [all] => (all, all, all, all),
+        [top_left_bottom_right, top_right_bottom_left] => (
+            top_left_bottom_right,
+            top_right_bottom_left,
+            top_left_bottom_right,
+            top_right_bottom_left,
+        ),
+        [top_left, top_right_bottom_left, bottom_right] => (
+            top_left,
+            top_right_bottom_left,
+            bottom_right,
+            top_right_bottom_left,
+        ),
+        [top_left, top_right, bottom_right, bottom_left] => {
+            (top_left, top_right, bottom_right, bottom_left)
         }
+        _ => return Err(format!("invalid BorderRadius `{radius}`")),
+    };
+    Ok(BorderRadius {
+        top_left,
+        top_right,
+        bottom_right,
+        bottom_left,
+    })
+}
+
+/// Parses CSS-style shadows, e.g. `"0 4px 8px rgba(0, 0, 0, 0.25)"`, where the blur and spread
+/// radii and the color are optional, or `"none"`.
+fn parse_box_shadow(shadow: &str) -> Result<BoxShadow, String> {
+    if shadow == "none" {
+        return Ok(BoxShadow::default());
     }
-    if let Some((val, "")) = val.split_once("vw") {
-        if let Ok(val) = val.parse::<f32>() {
-            return Val::Vw(val);
+    let mut vals = Vec::new();
+    let mut color = None;
+    for part in split_top_level(shadow, char::is_whitespace) {
+        match parse_val(part) {
+            Ok(val) => vals.push(val),
+            Err(_) if color.is_none() => color = Some(parse_color(part)?),
+            Err(error) => return Err(error),
         }
     }
-    if let Some((val, "")) = val.split_once("vh") {
-        if let Ok(val) = val.parse::<f32>() {
-            return Val::Vh(val);
+    let zero = Val::Px(0.0);
+    let (x_offset, y_offset, blur_radius, spread_radius) = match vals[..] {
+        [x, y] => (x, y, zero, zero),
+        [x, y, blur] => (x, y, blur, zero),
+        [x, y, blur, spread] => (x, y, blur, spread),
+        _ => return Err(format!("invalid BoxShadow `{shadow}`")),
+    };
+    Ok(BoxShadow {
+        color: color.unwrap_or(Color::BLACK),
+        x_offset,
+        y_offset,
+        blur_radius,
+        spread_radius,
+    })
+}
+
+fn parse_display(display: &str) -> Result<Display, String> {
+    match display {
+        "flex" => Ok(Display::Flex),
+        "grid" => Ok(Display::Grid),
+        "none" => Ok(Display::None),
+        _ => Err(format!("invalid Display `{display}`")),
+    }
+}
+
+fn parse_position_type(position: &str) -> Result<PositionType, String> {
+    match position {
+        "relative" => Ok(PositionType::Relative),
+        "absolute" => Ok(PositionType::Absolute),
+        _ => Err(format!("invalid PositionType `{position}`")),
+    }
+}
+
+/// Parses one value for both axes, or two space-separated values for x and y.
+fn parse_overflow(overflow: &str) -> Result<Overflow, String> {
+    match overflow.split_once(char::is_whitespace) {
+        Some((x, y)) => Ok(Overflow {
+            x: parse_overflow_axis(x.trim())?,
+            y: parse_overflow_axis(y.trim())?,
+        }),
+        None => {
+            let axis = parse_overflow_axis(overflow)?;
+            Ok(Overflow { x: axis, y: axis })
         }
     }
-    panic!("Encountered invalid bevy_dioxus Val `{val}`.");
+}
+
+fn parse_overflow_axis(axis: &str) -> Result<OverflowAxis, String> {
+    match axis {
+        "visible" => Ok(OverflowAxis::Visible),
+        "clip" => Ok(OverflowAxis::Clip),
+        _ => Err(format!("invalid OverflowAxis `{axis}`")),
+    }
+}
+
+fn parse_direction(direction: &str) -> Result<Direction, String> {
+    match direction {
+        "inherit" => Ok(Direction::Inherit),
+        "left_to_right" => Ok(Direction::LeftToRight),
+        "right_to_left" => Ok(Direction::RightToLeft),
+        _ => Err(format!("invalid Direction `{direction}`")),
+    }
+}
+
+fn parse_aspect_ratio(aspect_ratio: &str) -> Result<Option<f32>, String> {
+    match aspect_ratio {
+        "none" => Ok(None),
+        _ => Ok(Some(parse_f32(aspect_ratio)?)),
+    }
+}
+
+fn parse_align_items(align: &str) -> Result<AlignItems, String> {
+    match align {
+        "default" => Ok(AlignItems::Default),
+        "start" => Ok(AlignItems::Start),
+        "end" => Ok(AlignItems::End),
+        "flex_start" => Ok(AlignItems::FlexStart),
+        "flex_end" => Ok(AlignItems::FlexEnd),
+        "center" => Ok(AlignItems::Center),
+        "baseline" => Ok(AlignItems::Baseline),
+        "stretch" => Ok(AlignItems::Stretch),
+        _ => Err(format!("invalid AlignItems `{align}`")),
+    }
+}
+
+fn parse_justify_items(justify: &str) -> Result<JustifyItems, String> {
+    match justify {
+        "default" => Ok(JustifyItems::Default),
+        "start" => Ok(JustifyItems::Start),
+        "end" => Ok(JustifyItems::End),
+        "center" => Ok(JustifyItems::Center),
+        "baseline" => Ok(JustifyItems::Baseline),
+        "stretch" => Ok(JustifyItems::Stretch),
+        _ => Err(format!("invalid JustifyItems `{justify}`")),
+    }
+}
+
+fn parse_align_self(align: &str) -> Result<AlignSelf, String> {
+    match align {
+        "auto" => Ok(AlignSelf::Auto),
+        "start" => Ok(AlignSelf::Start),
+        "end" => Ok(AlignSelf::End),
+        "flex_start" => Ok(AlignSelf::FlexStart),
+        "flex_end" => Ok(AlignSelf::FlexEnd),
+        "center" => Ok(AlignSelf::Center),
+        "baseline" => Ok(AlignSelf::Baseline),
+        "stretch" => Ok(AlignSelf::Stretch),
+        _ => Err(format!("invalid AlignSelf `{align}`")),
+    }
+}
+
+fn parse_justify_self(justify: &str) -> Result<JustifySelf, String> {
+    match justify {
+        "auto" => Ok(JustifySelf::Auto),
+        "start" => Ok(JustifySelf::Start),
+        "end" => Ok(JustifySelf::End),
+        "center" => Ok(JustifySelf::Center),
+        "baseline" => Ok(JustifySelf::Baseline),
+        "stretch" => Ok(JustifySelf::Stretch),
+        _ => Err(format!("invalid JustifySelf `{justify}`")),
+    }
+}
+
+fn parse_align_content(align: &str) -> Result<AlignContent, String> {
+    match align {
+        "default" => Ok(AlignContent::Default),
+        "start" => Ok(AlignContent::Start),
+        "end" => Ok(AlignContent::End),
+        "flex_start" => Ok(AlignContent::FlexStart),
+        "flex_end" => Ok(AlignContent::FlexEnd),
+        "center" => Ok(AlignContent::Center),
+        "stretch" => Ok(AlignContent::Stretch),
+        "space_between" => Ok(AlignContent::SpaceBetween),
+        "space_evenly" => Ok(AlignContent::SpaceEvenly),
+        "space_around" => Ok(AlignContent::SpaceAround),
+        _ => Err(format!("invalid AlignContent `{align}`")),
+    }
+}
+
+fn parse_justify_content(justify: &str) -> Result<JustifyContent, String> {
+    match justify {
+        "default" => Ok(JustifyContent::Default),
+        "start" => Ok(JustifyContent::Start),
+        "end" => Ok(JustifyContent::End),
+        "flex_start" => Ok(JustifyContent::FlexStart),
+        "flex_end" => Ok(JustifyContent::FlexEnd),
+        "center" => Ok(JustifyContent::Center),
+        "stretch" => Ok(JustifyContent::Stretch),
+        "space_between" => Ok(JustifyContent::SpaceBetween),
+        "space_evenly" => Ok(JustifyContent::SpaceEvenly),
+        "space_around" => Ok(JustifyContent::SpaceAround),
+        _ => Err(format!("invalid JustifyContent `{justify}`")),
+    }
+}
+
+fn parse_flex_direction(direction: &str) -> Result<FlexDirection, String> {
+    match direction {
+        "row" => Ok(FlexDirection::Row),
+        "column" => Ok(FlexDirection::Column),
+        "row_reverse" => Ok(FlexDirection::RowReverse),
+        "column_reverse" => Ok(FlexDirection::ColumnReverse),
+        _ => Err(format!("invalid FlexDirection `{direction}`")),
+    }
+}
+
+fn parse_flex_wrap(wrap: &str) -> Result<FlexWrap, String> {
+    match wrap {
+        "no_wrap" => Ok(FlexWrap::NoWrap),
+        "wrap" => Ok(FlexWrap::Wrap),
+        "wrap_reverse" => Ok(FlexWrap::WrapReverse),
+        _ => Err(format!("invalid FlexWrap `{wrap}`")),
+    }
+}
+
+fn parse_grid_auto_flow(flow: &str) -> Result<GridAutoFlow, String> {
+    match flow {
+        "row" => Ok(GridAutoFlow::Row),
+        "column" => Ok(GridAutoFlow::Column),
+        "row_dense" => Ok(GridAutoFlow::RowDense),
+        "column_dense" => Ok(GridAutoFlow::ColumnDense),
+        _ => Err(format!("invalid GridAutoFlow `{flow}`")),
+    }
+}
+
+/// Parses space-separated tracks, which can be grouped with `repeat(count, tracks...)`, where
+/// the count is a number, `auto_fill` or `auto_fit`, e.g. `"200px repeat(auto_fill, 1fr)"`.
+fn parse_repeated_grid_tracks(tracks: &str) -> Result<Vec<RepeatedGridTrack>, String> {
+    split_top_level(tracks, char::is_whitespace)
+        .map(|track| -> Result<RepeatedGridTrack, String> {
+            let Some(arguments) = track
+                .strip_prefix("repeat(")
+                .and_then(|track| track.strip_suffix(')'))
+            else {
+                return Ok(parse_grid_track(track)?.into());
+            };
+            let (repetition, tracks) = arguments
+                .split_once(',')
+                .ok_or_else(|| format!("invalid RepeatedGridTrack `{track}`"))?;
+            let repetition = match repetition.trim() {
+                "auto_fill" => GridTrackRepetition::AutoFill,
+                "auto_fit" => GridTrackRepetition::AutoFit,
+                count => GridTrackRepetition::Count(
+                    count
+                        .parse::<u16>()
+                        .map_err(|_| format!("invalid GridTrackRepetition `{count}`"))?,
+                ),
+            };
+            Ok(RepeatedGridTrack::repeat_many(
+                repetition,
+                parse_grid_tracks(tracks.trim())?,
+            ))
+        })
+        .collect()
+}
+
+fn parse_grid_tracks(tracks: &str) -> Result<Vec<GridTrack>, String> {
+    split_top_level(tracks, char::is_whitespace)
+        .map(parse_grid_track)
+        .collect()
+}
+
+/// Parses `auto`, `min_content`, `max_content`, lengths, `fr` fractions,
+/// `fit_content(length)` and `minmax(min, max)`.
+fn parse_grid_track(track: &str) -> Result<GridTrack, String> {
+    if let Some(arguments) = track
+        .strip_prefix("minmax(")
+        .and_then(|track| track.strip_suffix(')'))
+    {
+        let (min, max) = arguments
+            .split_once(',')
+            .ok_or_else(|| format!("invalid GridTrack `{track}`"))?;
+        return Ok(GridTrack::minmax(
+            parse_min_track_sizing_function(min.trim())?,
+            parse_max_track_sizing_function(max.trim())?,
+        ));
+    }
+    match parse_max_track_sizing_function(track)? {
+        MaxTrackSizingFunction::Px(px) => Ok(GridTrack::px(px)),
+        MaxTrackSizingFunction::Percent(percent) => Ok(GridTrack::percent(percent)),
+        MaxTrackSizingFunction::MinContent => Ok(GridTrack::min_content()),
+        MaxTrackSizingFunction::MaxContent => Ok(GridTrack::max_content()),
+        MaxTrackSizingFunction::FitContentPx(px) => Ok(GridTrack::fit_content_px(px)),
+        MaxTrackSizingFunction::FitContentPercent(percent) => {
+            Ok(GridTrack::fit_content_percent(percent))
+        }
+        MaxTrackSizingFunction::Auto => Ok(GridTrack::auto()),
+        MaxTrackSizingFunction::Fraction(fraction) => Ok(GridTrack::fr(fraction)),
+    }
+}
+
+fn parse_min_track_sizing_function(min: &str) -> Result<MinTrackSizingFunction, String> {
+    match parse_max_track_sizing_function(min)? {
+        MaxTrackSizingFunction::Px(px) => Ok(MinTrackSizingFunction::Px(px)),
+        MaxTrackSizingFunction::Percent(percent) => Ok(MinTrackSizingFunction::Percent(percent)),
+        MaxTrackSizingFunction::MinContent => Ok(MinTrackSizingFunction::MinContent),
+        MaxTrackSizingFunction::MaxContent => Ok(MinTrackSizingFunction::MaxContent),
+        MaxTrackSizingFunction::Auto => Ok(MinTrackSizingFunction::Auto),
+        _ => Err(format!("invalid MinTrackSizingFunction `{min}`")),
+    }
+}
+
+fn parse_max_track_sizing_function(max: &str) -> Result<MaxTrackSizingFunction, String> {
+    if let Some(limit) = max
+        .strip_prefix("fit_content(")
+        .and_then(|max| max.strip_suffix(')'))
+    {
+        return match parse_val(limit.trim())? {
+            Val::Px(px) => Ok(MaxTrackSizingFunction::FitContentPx(px)),
+            Val::Percent(percent) => Ok(MaxTrackSizingFunction::FitContentPercent(percent)),
+            _ => Err(format!("invalid MaxTrackSizingFunction `{max}`")),
+        };
+    }
+    if let Some(fraction) = max.strip_suffix("fr") {
+        return Ok(MaxTrackSizingFunction::Fraction(parse_f32(fraction)?));
+    }
+    match max {
+        "auto" => Ok(MaxTrackSizingFunction::Auto),
+        "min_content" => Ok(MaxTrackSizingFunction::MinContent),
+        "max_content" => Ok(MaxTrackSizingFunction::MaxContent),
+        _ => match parse_val(max)? {
+            Val::Px(px) => Ok(MaxTrackSizingFunction::Px(px)),
+            Val::Percent(percent) => Ok(MaxTrackSizingFunction::Percent(percent)),
+            _ => Err(format!("invalid MaxTrackSizingFunction `{max}`")),
+        },
+    }
+}
+
+/// Parses CSS-style placements, e.g. `"auto"`, `"2"`, `"span 3"`, `"1 / 3"` or `"2 / span 2"`.
+fn parse_grid_placement(placement: &str) -> Result<GridPlacement, String> {
+    let invalid = || format!("invalid GridPlacement `{placement}`");
+    let mut grid_placement = GridPlacement::auto();
+    let (start, end) = match placement.split_once('/') {
+        Some((start, end)) => (start.trim(), Some(end.trim())),
+        None => (placement.trim(), None),
+    };
+    for (line, is_start) in [(start, true), (end.unwrap_or("auto"), false)] {
+        if line == "auto" {
+            continue;
+        }
+        if let Some(span) = line.strip_prefix("span") {
+            let span = span.trim().parse::<u16>().map_err(|_| invalid())?;
+            if span == 0 {
+                return Err(invalid());
+            }
+            grid_placement = grid_placement.set_span(span);
+        } else {
+            let index = line.parse::<i16>().map_err(|_| invalid())?;
+            if index == 0 {
+                return Err(invalid());
+            }
+            grid_placement = if is_start {
+                grid_placement.set_start(index)
+            } else {
+                grid_placement.set_end(index)
+            };
+        }
+    }
+    Ok(grid_placement)
+}
+
+/// Splits on `separator`, except inside parentheses.
+fn split_top_level(value: &str, separator: fn(char) -> bool) -> impl Iterator<Item = &str> {
+    let mut depth = 0;
+    value
+        .split(move |c: char| {
+            match c {
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                _ => {}
+            }
+            depth == 0 && separator(c)
+        })
+        .filter(|part| !part.is_empty())
+}
+
+fn parse_slice_scale_mode(mode: &str) -> Result<SliceScaleMode, String> {
+    match mode.split_once(':') {
+        Some(("tile", value)) => Ok(SliceScaleMode::Tile {
+            stretch_value: parse_f32(value)?,
+        }),
+        None if mode == "tile" => Ok(SliceScaleMode::Tile { stretch_value: 1.0 }),
+        None if mode == "stretch" => Ok(SliceScaleMode::Stretch),
+        _ => Err(format!("invalid SliceScaleMode `{mode}`")),
+    }
+}
+
+fn texture_slicer(scale_mode: &mut Option<ImageScaleMode>) -> &mut TextureSlicer {
+    if !matches!(scale_mode, Some(ImageScaleMode::Sliced(_))) {
+        *scale_mode = Some(ImageScaleMode::Sliced(TextureSlicer::default()));
+    }
+    match scale_mode {
+        Some(ImageScaleMode::Sliced(slicer)) => slicer,
+        _ => unreachable!(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::{
+        app::App, asset::AssetPlugin, render::texture::Image, sprite::TextureAtlasLayout,
+        MinimalPlugins,
+    };
+
+    fn asset_server() -> AssetServer {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()));
+        app.world.resource::<AssetServer>().clone()
+    }
+
+    fn set_image_attribute(
+        image: &mut ImageComponents,
+        name: &str,
+        value: AttributeInput,
+    ) -> Result<(), String> {
+        set_attribute(
+            name,
+            value,
+            AttributeTargets {
+                style: &mut Style::default(),
+                border_color: &mut BorderColor::default(),
+                outline: &mut Outline::default(),
+                border_radius: &mut BorderRadius::default(),
+                box_shadow: &mut BoxShadow::default(),
+                rounded_box: None,
+                background_color: Some(&mut BackgroundColor::default()),
+                transform: &mut UiTransform::default(),
+                visibility: &mut Visibility::default(),
+                z_index: &mut ZIndex::default(),
+                text: None,
+                image: Some(image),
+            },
+            &asset_server(),
+        )
+    }
+
+    #[test]
+    fn parses_slice_scale_modes() {
+        assert!(matches!(
+            parse_slice_scale_mode("stretch"),
+            Ok(SliceScaleMode::Stretch)
+        ));
+        assert!(matches!(
+            parse_slice_scale_mode("tile"),
+            Ok(SliceScaleMode::Tile { stretch_value }) if stretch_value == 1.0
+        ));
+        assert!(matches!(
+            parse_slice_scale_mode("tile:0.5"),
+            Ok(SliceScaleMode::Tile { stretch_value }) if stretch_value == 0.5
+        ));
+        assert!(parse_slice_scale_mode("tile:x").is_err());
+        assert!(parse_slice_scale_mode("stretch:2").is_err());
+        assert!(parse_slice_scale_mode("repeat").is_err());
+    }
+
+    #[test]
+    fn sets_image_scale_modes() {
+        fn set(image: &mut ImageComponents, mode: &str) -> Result<(), String> {
+            set_image_attribute(image, "image_scale_mode", AttributeInput::Text(mode))
+        }
+
+        let mut image = ImageComponents::default();
+        assert_eq!(set(&mut image, "tiled_x"), Ok(()));
+        assert!(matches!(
+            image.scale_mode,
+            Some(ImageScaleMode::Tiled {
+                tile_x: true,
+                tile_y: false,
+                ..
+            })
+        ));
+        assert_eq!(set(&mut image, "sliced"), Ok(()));
+        assert!(matches!(image.scale_mode, Some(ImageScaleMode::Sliced(_))));
+        assert_eq!(set(&mut image, "stretch"), Ok(()));
+        assert!(image.scale_mode.is_none());
+        assert!(set(&mut image, "fill").is_err());
+        assert!(image.scale_mode.is_none());
+    }
+
+    #[test]
+    fn sets_texture_slicer_fields() {
+        let mut image = ImageComponents::default();
+        for (name, value) in [
+            ("image_slice_border", "8"),
+            ("image_slice_border_left", "4"),
+            ("image_slice_center_scale_mode", "tile:2"),
+            ("image_slice_sides_scale_mode", "stretch"),
+            ("image_slice_max_corner_scale", "0.5"),
+        ] {
+            assert_eq!(
+                set_image_attribute(&mut image, name, AttributeInput::Text(value)),
+                Ok(()),
+                "{name}: {value}"
+            );
+        }
+        let Some(ImageScaleMode::Sliced(slicer)) = &image.scale_mode else {
+            panic!("slice attributes should make the image sliced");
+        };
+        assert_eq!(slicer.border.left, 4.0);
+        assert_eq!(slicer.border.right, 8.0);
+        assert_eq!(slicer.border.top, 8.0);
+        assert_eq!(slicer.border.bottom, 8.0);
+        assert!(matches!(
+            slicer.center_scale_mode,
+            SliceScaleMode::Tile { stretch_value } if stretch_value == 2.0
+        ));
+        assert!(matches!(slicer.sides_scale_mode, SliceScaleMode::Stretch));
+        assert_eq!(slicer.max_corner_scale, 0.5);
+        assert!(set_image_attribute(
+            &mut image,
+            "image_slice_border_top",
+            AttributeInput::Text("wide")
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn keeps_atlas_index_set_before_layout() {
+        let layout = AssetHandle(Handle::<TextureAtlasLayout>::weak_from_u128(1));
+        let mut image = ImageComponents::default();
+        let index = AttributeInput::Text("3");
+        assert_eq!(
+            set_image_attribute(&mut image, "image_texture_atlas_index", index),
+            Ok(())
+        );
+        assert!(image.texture_atlas.is_none());
+        assert_eq!(
+            set_image_attribute(
+                &mut image,
+                "image_texture_atlas_layout",
+                AttributeInput::Any(&layout)
+            ),
+            Ok(())
+        );
+        let texture_atlas = image.texture_atlas.as_ref().unwrap();
+        assert_eq!(texture_atlas.layout, layout.0);
+        assert_eq!(texture_atlas.index, 3);
+        assert_eq!(image.pending_texture_atlas_index, None);
+
+        let index = AttributeInput::Text("5");
+        assert_eq!(
+            set_image_attribute(&mut image, "image_texture_atlas_index", index),
+            Ok(())
+        );
+        assert_eq!(image.texture_atlas.as_ref().unwrap().index, 5);
+        assert_eq!(
+            set_image_attribute(
+                &mut image,
+                "image_texture_atlas_layout",
+                AttributeInput::Text("none")
+            ),
+            Ok(())
+        );
+        assert!(image.texture_atlas.is_none());
+        assert_eq!(image.pending_texture_atlas_index, Some(5));
+    }
+
+    #[test]
+    fn rejects_invalid_atlas_attributes() {
+        let mut image = ImageComponents::default();
+        for (name, value) in [
+            ("image_texture_atlas_index", "-1"),
+            ("image_texture_atlas_index", "first"),
+            ("image_texture_atlas_layout", "atlas.ron"),
+        ] {
+            assert!(set_image_attribute(&mut image, name, AttributeInput::Text(value)).is_err());
+        }
+        let texture = AssetHandle(Handle::<Image>::weak_from_u128(1));
+        assert!(set_image_attribute(
+            &mut image,
+            "image_texture_atlas_layout",
+            AttributeInput::Any(&texture)
+        )
+        .is_err());
+        assert!(image.texture_atlas.is_none());
+        assert_eq!(image.pending_texture_atlas_index, None);
+    }
+
+    #[test]
+    fn parses_vals() {
+        assert_eq!(parse_val("auto"), Ok(Val::Auto));
+        assert_eq!(parse_val("10"), Ok(Val::Px(10.0)));
+        assert_eq!(parse_val("10.5px"), Ok(Val::Px(10.5)));
+        assert_eq!(parse_val("50%"), Ok(Val::Percent(50.0)));
+        assert_eq!(parse_val("100vw"), Ok(Val::Vw(100.0)));
+        assert_eq!(parse_val("100vh"), Ok(Val::Vh(100.0)));
+        assert_eq!(parse_val("10vmin"), Ok(Val::VMin(10.0)));
+        assert_eq!(parse_val("10vmax"), Ok(Val::VMax(10.0)));
+        assert!(parse_val("").is_err());
+        assert!(parse_val("px").is_err());
+        assert!(parse_val("10em").is_err());
+        assert!(parse_val("ten").is_err());
+    }
+
+    #[test]
+    fn parses_ui_rects() {
+        let px = Val::Px;
+        assert_eq!(parse_ui_rect("4"), Ok(UiRect::all(px(4.0))));
+        assert_eq!(
+            parse_ui_rect("4 8"),
+            Ok(UiRect::new(px(8.0), px(8.0), px(4.0), px(4.0)))
+        );
+        assert_eq!(
+            parse_ui_rect("4 8 2"),
+            Ok(UiRect::new(px(8.0), px(8.0), px(4.0), px(2.0)))
+        );
+        assert_eq!(
+            parse_ui_rect("4 8 2 0"),
+            Ok(UiRect::new(px(0.0), px(8.0), px(4.0), px(2.0)))
+        );
+        assert!(parse_ui_rect("").is_err());
+        assert!(parse_ui_rect("1 2 3 4 5").is_err());
+        assert!(parse_ui_rect("4 x").is_err());
+    }
+
+    #[test]
+    fn parses_colors() {
+        let violet = Color::hex(colors::VIOLET_500).unwrap();
+        assert_eq!(parse_color("#8b5cf6"), Ok(violet));
+        assert_eq!(parse_color("violet-500"), Ok(violet));
+        assert_eq!(parse_color("red"), Ok(Color::hex("#ff0000").unwrap()));
+        assert_eq!(parse_color("violet-500/50"), Ok(violet.with_a(0.5)));
+        assert_eq!(parse_color("rgb(255, 0, 0)"), Ok(Color::rgb(1.0, 0.0, 0.0)));
+        assert_eq!(
+            parse_color("rgba(255 0 0 / 50%)"),
+            Ok(Color::rgba(1.0, 0.0, 0.0, 0.5))
+        );
+        assert_eq!(
+            parse_color("rgb(100% 0% 0% / 0.25)"),
+            Ok(Color::rgba(1.0, 0.0, 0.0, 0.25))
+        );
+        assert_eq!(
+            parse_color("hsl(120deg, 100%, 50%)"),
+            Ok(Color::hsl(120.0, 1.0, 0.5))
+        );
+        assert_eq!(
+            parse_color("hsla(480 100% 50% / 50%)"),
+            Ok(Color::hsla(120.0, 1.0, 0.5, 0.5))
+        );
+        assert!(parse_color("rgb(1, 2)").is_err());
+        assert!(parse_color("cmyk(0, 0, 0, 0)").is_err());
+        assert!(parse_color("notacolor").is_err());
+        assert!(parse_color("red/abc").is_err());
+    }
+
+    fn assert_angle(angle: &str, radians: f32) {
+        let parsed = parse_angle(angle).unwrap();
+        assert!(
+            (parsed - radians).abs() < 1e-6,
+            "{angle} parsed as {parsed}"
+        );
+    }
+
+    #[test]
+    fn parses_angles() {
+        assert_angle("90", TAU / 4.0);
+        assert_angle("90deg", TAU / 4.0);
+        assert_angle("-45deg", -TAU / 8.0);
+        assert_angle("1rad", 1.0);
+        assert_angle("0.5turn", TAU / 2.0);
+        assert!(parse_angle("").is_err());
+        assert!(parse_angle("90grad").is_err());
+        assert!(parse_angle("right").is_err());
+    }
+
+    #[test]
+    fn parses_transform_origins() {
+        let percent = Val::Percent;
+        assert_eq!(
+            parse_transform_origin("center"),
+            Ok((percent(50.0), percent(50.0)))
+        );
+        assert_eq!(
+            parse_transform_origin("left"),
+            Ok((percent(0.0), percent(50.0)))
+        );
+        assert_eq!(
+            parse_transform_origin("bottom"),
+            Ok((percent(50.0), percent(100.0)))
+        );
+        assert_eq!(
+            parse_transform_origin("left top"),
+            Ok((percent(0.0), percent(0.0)))
+        );
+        assert_eq!(
+            parse_transform_origin("top right"),
+            Ok((percent(100.0), percent(0.0)))
+        );
+        assert_eq!(
+            parse_transform_origin("25% 10px"),
+            Ok((percent(25.0), Val::Px(10.0)))
+        );
+        assert!(parse_transform_origin("middle").is_err());
+        assert!(parse_transform_origin("left up").is_err());
+    }
+
+    #[test]
+    fn parses_grid_tracks() {
+        let tracks: Vec<GridTrack> = vec![
+            GridTrack::px(100.0),
+            GridTrack::fr(1.0),
+            GridTrack::auto(),
+            GridTrack::min_content(),
+            GridTrack::minmax(
+                MinTrackSizingFunction::Px(50.0),
+                MaxTrackSizingFunction::Fraction(2.0),
+            ),
+            GridTrack::fit_content_percent(20.0),
+        ];
+        assert_eq!(
+            parse_grid_tracks("100px 1fr auto min_content minmax(50px, 2fr) fit_content(20%)"),
+            Ok(tracks)
+        );
+        assert!(parse_grid_tracks("minmax(1fr, 2fr)").is_err());
+        assert!(parse_grid_tracks("minmax(10px)").is_err());
+        assert!(parse_grid_tracks("fit_content(10vw)").is_err());
+        assert!(parse_grid_tracks("10em").is_err());
+    }
+
+    #[test]
+    fn parses_repeated_grid_tracks() {
+        let tracks: Vec<RepeatedGridTrack> = vec![
+            GridTrack::px(200.0),
+            RepeatedGridTrack::repeat_many(
+                GridTrackRepetition::AutoFill,
+                vec![GridTrack::fr::<GridTrack>(1.0)],
+            ),
+            RepeatedGridTrack::repeat_many(
+                GridTrackRepetition::Count(3),
+                vec![
+                    GridTrack::px::<GridTrack>(10.0),
+                    GridTrack::minmax::<GridTrack>(
+                        MinTrackSizingFunction::Auto,
+                        MaxTrackSizingFunction::Fraction(1.0),
+                    ),
+                ],
+            ),
+        ];
+        assert_eq!(
+            parse_repeated_grid_tracks(
+                "200px repeat(auto_fill, 1fr) repeat(3, 10px minmax(auto, 1fr))"
+            ),
+            Ok(tracks)
+        );
+        assert!(parse_repeated_grid_tracks("repeat(1fr)").is_err());
+        assert!(parse_repeated_grid_tracks("repeat(-1, 1fr)").is_err());
+        assert!(parse_repeated_grid_tracks("repeat(auto_fill, 10em)").is_err());
+    }
+
+    #[test]
+    fn parses_grid_placements() {
+        let placement = parse_grid_placement("auto").unwrap();
+        assert_eq!(placement.get_start(), None);
+        assert_eq!(placement.get_end(), None);
+
+        let placement = parse_grid_placement("-1").unwrap();
+        assert_eq!(placement.get_start(), Some(-1));
+
+        let placement = parse_grid_placement("span 3").unwrap();
+        assert_eq!(placement.get_start(), None);
+        assert_eq!(placement.get_span(), Some(3));
+
+        let placement = parse_grid_placement("1 / 3").unwrap();
+        assert_eq!(placement.get_start(), Some(1));
+        assert_eq!(placement.get_end(), Some(3));
+
+        let placement = parse_grid_placement("2 / span 2").unwrap();
+        assert_eq!(placement.get_start(), Some(2));
+        assert_eq!(placement.get_span(), Some(2));
+
+        assert!(parse_grid_placement("0").is_err());
+        assert!(parse_grid_placement("span 0").is_err());
+        assert!(parse_grid_placement("1 / x").is_err());
+    }
+
+    #[test]
+    fn splits_top_level() {
+        fn split(value: &str, separator: fn(char) -> bool) -> Vec<&str> {
+            split_top_level(value, separator).collect()
+        }
+        assert_eq!(
+            split("a  (b c) d", char::is_whitespace),
+            ["a", "(b c)", "d"]
+        );
+        assert_eq!(
+            split("f(g(a b) c) d", char::is_whitespace),
+            ["f(g(a b) c)", "d"]
+        );
+        assert_eq!(
+            split("minmax(1px, 2px),3px", |c| c == ','),
+            ["minmax(1px, 2px)", "3px"]
+        );
+        assert!(split("", char::is_whitespace).is_empty());
+    }
+
+    #[test]
+    fn parses_border_radii() {
+        let px = Val::Px;
+        assert_eq!(parse_border_radius("4"), Ok(BorderRadius::all(px(4.0))));
+        assert_eq!(
+            parse_border_radius("4 50%"),
+            Ok(BorderRadius {
+                top_left: px(4.0),
+                top_right: Val::Percent(50.0),
+                bottom_right: px(4.0),
+                bottom_left: Val::Percent(50.0),
+            })
+        );
+        assert_eq!(
+            parse_border_radius("1 2 3"),
+            Ok(BorderRadius {
+                top_left: px(1.0),
+                top_right: px(2.0),
+                bottom_right: px(3.0),
+                bottom_left: px(2.0),
+            })
+        );
+        assert_eq!(
+            parse_border_radius("1 2 3 4"),
+            Ok(BorderRadius {
+                top_left: px(1.0),
+                top_right: px(2.0),
+                bottom_right: px(3.0),
+                bottom_left: px(4.0),
+            })
+        );
+        assert!(parse_border_radius("").is_err());
+        assert!(parse_border_radius("1 2 3 4 5").is_err());
+        assert!(parse_border_radius("round").is_err());
+    }
+
+    #[test]
+    fn parses_box_shadows() {
+        let px = Val::Px;
+        assert_eq!(parse_box_shadow("none"), Ok(BoxShadow::default()));
+        assert_eq!(
+            parse_box_shadow("0 4px 8px rgba(0, 0, 0, 0.25)"),
+            Ok(BoxShadow {
+                color: Color::rgba(0.0, 0.0, 0.0, 0.25),
+                x_offset: px(0.0),
+                y_offset: px(4.0),
+                blur_radius: px(8.0),
+                spread_radius: px(0.0),
+            })
+        );
+        assert_eq!(
+            parse_box_shadow("red 1 2 3 -1"),
+            Ok(BoxShadow {
+                color: Color::hex("#ff0000").unwrap(),
+                x_offset: px(1.0),
+                y_offset: px(2.0),
+                blur_radius: px(3.0),
+                spread_radius: px(-1.0),
+            })
+        );
+        assert_eq!(
+            parse_box_shadow("2px 2px"),
+            Ok(BoxShadow {
+                color: Color::BLACK,
+                x_offset: px(2.0),
+                y_offset: px(2.0),
+                ..BoxShadow::default()
+            })
+        );
+        assert!(parse_box_shadow("4px").is_err());
+        assert!(parse_box_shadow("1 2 3 4 5").is_err());
+        assert!(parse_box_shadow("1 2 red blue").is_err());
+    }
 }
@@ -0,0 +1,97 @@
+#import bevy_render::view::View
+
+struct RoundedBox {
+    background_color: vec4<f32>,
+    border_color: vec4<f32>,
+    shadow_color: vec4<f32>,
+    // Left, right, top and bottom
+    border_widths: vec4<f32>,
+    // Top left, top right, bottom right and bottom left
+    border_radii: vec4<f32>,
+    // The node's local axes, to grow the quad along them when the node is rotated or scaled
+    x_axis: vec2<f32>,
+    y_axis: vec2<f32>,
+    size: vec2<f32>,
+    shadow_offset: vec2<f32>,
+    shadow_blur: f32,
+    shadow_spread: f32,
+    extent: f32,
+}
+
+@group(0) @binding(0) var<uniform> view: View;
+@group(1) @binding(0) var<uniform> rounded_box: RoundedBox;
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@vertex
+fn vertex(
+    @location(0) vertex_position: vec3<f32>,
+    @location(1) vertex_uv: vec2<f32>,
+) -> VertexOutput {
+    let direction = (vertex_uv - 0.5) * 2.0 * rounded_box.extent;
+    let offset = rounded_box.x_axis * direction.x + rounded_box.y_axis * direction.y;
+    var out: VertexOutput;
+    out.position = view.view_proj * vec4<f32>(vertex_position.xy + offset, vertex_position.z, 1.0);
+    out.uv = vertex_uv;
+    return out;
+}
+
+fn corner_radius(point: vec2<f32>, radii: vec4<f32>) -> f32 {
+    if point.y < 0.0 {
+        return select(radii.x, radii.y, point.x > 0.0);
+    }
+    return select(radii.w, radii.z, point.x > 0.0);
+}
+
+// Signed distance to the edge of a box centered on the origin, negative inside
+fn rounded_box_distance(point: vec2<f32>, half_size: vec2<f32>, radius: f32) -> f32 {
+    let corner = abs(point) - half_size + radius;
+    return length(max(corner, vec2(0.0))) + min(max(corner.x, corner.y), 0.0) - radius;
+}
+
+fn coverage(distance: f32) -> f32 {
+    return clamp(0.5 - distance, 0.0, 1.0);
+}
+
+fn premultiply(color: vec4<f32>) -> vec4<f32> {
+    return vec4(color.rgb * color.a, color.a);
+}
+
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    let point = (in.uv - 0.5) * (rounded_box.size + 2.0 * rounded_box.extent);
+    let half_size = rounded_box.size * 0.5;
+    let radius = corner_radius(point, rounded_box.border_radii);
+    let outer = coverage(rounded_box_distance(point, half_size, radius));
+
+    let widths = rounded_box.border_widths;
+    let inner_center = vec2(widths.x - widths.y, widths.z - widths.w) * 0.5;
+    let inner_half_size = max(half_size - vec2(widths.x + widths.y, widths.z + widths.w) * 0.5, vec2(0.0));
+    let adjacent_width = max(select(widths.x, widths.y, point.x > 0.0), select(widths.z, widths.w, point.y > 0.0));
+    let inner_radius = max(radius - adjacent_width, 0.0);
+    let inner = coverage(rounded_box_distance(point - inner_center, inner_half_size, inner_radius));
+
+    let fill = mix(premultiply(rounded_box.border_color), premultiply(rounded_box.background_color), inner) * outer;
+
+    // Like CSS, the shadow is only visible outside of the box
+    let shadow_point = point - rounded_box.shadow_offset;
+    let shadow_distance = rounded_box_distance(
+        shadow_point,
+        max(half_size + rounded_box.shadow_spread, vec2(0.0)),
+        max(corner_radius(shadow_point, rounded_box.border_radii) + rounded_box.shadow_spread, 0.0),
+    );
+    var shadow = coverage(shadow_distance);
+    if rounded_box.shadow_blur > 0.0 {
+        shadow = 1.0 - smoothstep(-0.5 * rounded_box.shadow_blur, 0.5 * rounded_box.shadow_blur, shadow_distance);
+    }
+    let shadow_color = premultiply(rounded_box.shadow_color) * shadow * (1.0 - outer);
+
+    let color = fill + shadow_color * (1.0 - fill.a);
+    if color.a <= 0.0 {
+        return vec4(0.0);
+    }
+    return vec4(color.rgb / color.a, color.a);
+}
@@ -0,0 +1,374 @@
+use crate::ui_materials::UiMaterialTag;
+use bevy::{
+    asset::{Asset, Assets, Handle},
+    ecs::{
+        component::Component,
+        entity::Entity,
+        query::{With, Without},
+        system::{Commands, Query, ResMut},
+        world::EntityWorldMut,
+    },
+    hierarchy::Parent,
+    math::{Vec2, Vec4},
+    reflect::TypePath,
+    render::{
+        color::Color,
+        render_resource::{AsBindGroup, Shader, ShaderRef, ShaderType},
+    },
+    transform::components::GlobalTransform,
+    ui::{BackgroundColor, BorderColor, Node, Style, UiImage, UiMaterial, Val},
+    window::{PrimaryWindow, Window},
+};
+use std::mem;
+
+pub const ROUNDED_BOX_SHADER_HANDLE: Handle<Shader> =
+    Handle::weak_from_u128(0x5d1c_3a0f_8e47_4b9a_a6f2_91c4_7e0b_d238);
+
+/// The radius of each corner of an element, like CSS `border-radius`.
+///
+/// Percentages are relative to the element's shorter side.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct BorderRadius {
+    pub top_left: Val,
+    pub top_right: Val,
+    pub bottom_right: Val,
+    pub bottom_left: Val,
+}
+
+impl BorderRadius {
+    pub const fn all(radius: Val) -> Self {
+        Self {
+            top_left: radius,
+            top_right: radius,
+            bottom_right: radius,
+            bottom_left: radius,
+        }
+    }
+}
+
+impl Default for BorderRadius {
+    fn default() -> Self {
+        Self::all(Val::Px(0.0))
+    }
+}
+
+/// A shadow drawn outside of an element, like CSS `box-shadow`.
+///
+/// Percentages are relative to the element's own size.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct BoxShadow {
+    pub color: Color,
+    pub x_offset: Val,
+    pub y_offset: Val,
+    pub blur_radius: Val,
+    pub spread_radius: Val,
+}
+
+impl Default for BoxShadow {
+    fn default() -> Self {
+        Self {
+            color: Color::NONE,
+            x_offset: Val::Px(0.0),
+            y_offset: Val::Px(0.0),
+            blur_radius: Val::Px(0.0),
+            spread_radius: Val::Px(0.0),
+        }
+    }
+}
+
+/// Draws the background, border and shadow of elements with rounded corners or a shadow, which
+/// Bevy UI can't draw itself.
+#[derive(Asset, TypePath, AsBindGroup, Clone, Default)]
+pub struct RoundedBoxMaterial {
+    #[uniform(0)]
+    rounded_box: RoundedBoxUniform,
+}
+
+#[derive(ShaderType, Clone, Copy, Default, PartialEq)]
+struct RoundedBoxUniform {
+    background_color: Vec4,
+    border_color: Vec4,
+    shadow_color: Vec4,
+    /// Left, right, top and bottom.
+    border_widths: Vec4,
+    /// Top left, top right, bottom right and bottom left.
+    border_radii: Vec4,
+    x_axis: Vec2,
+    y_axis: Vec2,
+    size: Vec2,
+    shadow_offset: Vec2,
+    shadow_blur: f32,
+    shadow_spread: f32,
+    /// How far the quad is grown on each side to fit the shadow.
+    extent: f32,
+}
+
+impl UiMaterial for RoundedBoxMaterial {
+    fn vertex_shader() -> ShaderRef {
+        ROUNDED_BOX_SHADER_HANDLE.into()
+    }
+
+    fn fragment_shader() -> ShaderRef {
+        ROUNDED_BOX_SHADER_HANDLE.into()
+    }
+}
+
+/// The background and border colors of an element with rounded corners or a shadow.
+///
+/// Bevy can only draw `BackgroundColor` and `BorderColor` with square corners, and only draws the
+/// materials of nodes without a `BackgroundColor`. So while an element has rounded corners or a
+/// shadow, its `BackgroundColor` is removed, its `BorderColor` is transparent, and its
+/// `background_color` and `border_color` attributes are kept here instead, where
+/// [`RoundedBoxMaterial`] draws them from.
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct RoundedBox {
+    pub background_color: Color,
+    pub border_color: Color,
+    is_active: bool,
+}
+
+impl RoundedBox {
+    /// Moves the element's colors here when it gets rounded corners or a shadow and back when it
+    /// loses them, and moves the color of `name` here if it was just set on a rounded element.
+    pub(crate) fn update(
+        &mut self,
+        name: &str,
+        border_radius: &BorderRadius,
+        box_shadow: &BoxShadow,
+        background_color: &mut BackgroundColor,
+        border_color: &mut BorderColor,
+    ) {
+        let is_active = is_rounded_box(border_radius, box_shadow);
+        if is_active != self.is_active {
+            if is_active {
+                self.background_color = mem::replace(&mut background_color.0, Color::NONE);
+                self.border_color = mem::replace(&mut border_color.0, Color::NONE);
+            } else {
+                background_color.0 = self.background_color;
+                border_color.0 = self.border_color;
+            }
+            self.is_active = is_active;
+        } else if is_active {
+            match name {
+                "background_color" => {
+                    self.background_color = mem::replace(&mut background_color.0, Color::NONE);
+                }
+                "border_color" => {
+                    self.border_color = mem::replace(&mut border_color.0, Color::NONE);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Removes the `BackgroundColor` of an element that just became a rounded box, and restores it
+/// once the element stops being one.
+pub(crate) fn update_background_color(entity: &mut EntityWorldMut) {
+    let Some(rounded_box) = entity.get::<RoundedBox>().copied() else {
+        return;
+    };
+    match (rounded_box.is_active, entity.contains::<BackgroundColor>()) {
+        (true, true) => {
+            entity.remove::<BackgroundColor>();
+        }
+        (false, false) => {
+            entity.insert(BackgroundColor(rounded_box.background_color));
+        }
+        _ => {}
+    }
+}
+
+fn is_rounded_box(border_radius: &BorderRadius, box_shadow: &BoxShadow) -> bool {
+    *border_radius != BorderRadius::default() || box_shadow.color.a() > 0.0
+}
+
+pub fn update_rounded_boxes(
+    mut commands: Commands,
+    nodes: Query<
+        (
+            Entity,
+            &Node,
+            &Style,
+            &BorderRadius,
+            &BoxShadow,
+            &RoundedBox,
+            &GlobalTransform,
+            Option<&Parent>,
+            Option<&Handle<RoundedBoxMaterial>>,
+        ),
+        (Without<UiImage>, Without<UiMaterialTag>),
+    >,
+    parent_nodes: Query<&Node>,
+    mut materials: ResMut<Assets<RoundedBoxMaterial>>,
+    primary_window: Query<&Window, With<PrimaryWindow>>,
+) {
+    let viewport_size = primary_window
+        .get_single()
+        .map(|window| Vec2::new(window.width(), window.height()))
+        .unwrap_or_default();
+    let resolve = |val: Val, size: f32| val.resolve(size, viewport_size).unwrap_or(0.0);
+
+    for (
+        entity,
+        node,
+        style,
+        border_radius,
+        box_shadow,
+        rounded_box,
+        global_transform,
+        parent,
+        material,
+    ) in &nodes
+    {
+        if !is_rounded_box(border_radius, box_shadow) {
+            if material.is_some() {
+                commands
+                    .entity(entity)
+                    .remove::<Handle<RoundedBoxMaterial>>();
+            }
+            continue;
+        }
+        let has_shadow = box_shadow.color.a() > 0.0;
+
+        let size = node.size();
+        let half_side = size.min_element() / 2.0;
+        let corner = |radius: Val| resolve(radius, size.min_element()).clamp(0.0, half_side);
+        // Like Bevy's layout, border percentages are relative to the parent's width
+        let parent_width = parent
+            .and_then(|parent| parent_nodes.get(parent.get()).ok())
+            .map_or(viewport_size.x, |parent| parent.size().x);
+        let border = |width: Val| resolve(width, parent_width).max(0.0);
+        let shadow_offset = Vec2::new(
+            resolve(box_shadow.x_offset, size.x),
+            resolve(box_shadow.y_offset, size.y),
+        );
+        let shadow_blur = resolve(box_shadow.blur_radius, size.x).max(0.0);
+        let shadow_spread = resolve(box_shadow.spread_radius, size.x);
+        let matrix = global_transform.affine().matrix3;
+
+        let rounded_box = RoundedBoxUniform {
+            background_color: Vec4::from(rounded_box.background_color.as_linear_rgba_f32()),
+            border_color: Vec4::from(rounded_box.border_color.as_linear_rgba_f32()),
+            shadow_color: Vec4::from(box_shadow.color.as_linear_rgba_f32()),
+            border_widths: Vec4::new(
+                border(style.border.left),
+                border(style.border.right),
+                border(style.border.top),
+                border(style.border.bottom),
+            ),
+            border_radii: Vec4::new(
+                corner(border_radius.top_left),
+                corner(border_radius.top_right),
+                corner(border_radius.bottom_right),
+                corner(border_radius.bottom_left),
+            ),
+            x_axis: matrix.x_axis.truncate(),
+            y_axis: matrix.y_axis.truncate(),
+            size,
+            shadow_offset,
+            shadow_blur,
+            shadow_spread,
+            extent: if has_shadow {
+                shadow_offset.abs().max_element() + shadow_blur + shadow_spread.max(0.0)
+            } else {
+                0.0
+            },
+        };
+
+        match material.and_then(|material| Some((material, materials.get(material)?))) {
+            Some((_, existing)) if existing.rounded_box == rounded_box => {}
+            Some((material, _)) => {
+                materials.get_mut(material).unwrap().rounded_box = rounded_box;
+            }
+            None => {
+                commands
+                    .entity(entity)
+                    .insert(materials.add(RoundedBoxMaterial { rounded_box }));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::{
+        ecs::{system::RunSystemOnce, world::World},
+        ui::node_bundles::NodeBundle,
+        utils::default,
+    };
+
+    fn set_border_radius(world: &mut World, entity: Entity, radius: BorderRadius) {
+        let (
+            mut rounded_box,
+            mut border_radius,
+            box_shadow,
+            mut background_color,
+            mut border_color,
+        ) = world
+            .query::<(
+                &mut RoundedBox,
+                &mut BorderRadius,
+                &BoxShadow,
+                Option<&mut BackgroundColor>,
+                &mut BorderColor,
+            )>()
+            .get_mut(world, entity)
+            .unwrap();
+        *border_radius = radius;
+        // The attribute layer writes to a copy while the element has no `BackgroundColor`
+        let mut detached_background_color = BackgroundColor(rounded_box.background_color);
+        rounded_box.update(
+            "border_radius",
+            &border_radius,
+            box_shadow,
+            background_color
+                .as_deref_mut()
+                .unwrap_or(&mut detached_background_color),
+            &mut border_color,
+        );
+        update_background_color(&mut world.entity_mut(entity));
+        world.run_system_once(update_rounded_boxes);
+    }
+
+    #[test]
+    fn rounded_node_has_extractable_material() {
+        let mut world = World::new();
+        world.init_resource::<Assets<RoundedBoxMaterial>>();
+        let entity = world
+            .spawn((
+                NodeBundle {
+                    background_color: BackgroundColor(Color::RED),
+                    ..default()
+                },
+                BorderRadius::default(),
+                BoxShadow::default(),
+                RoundedBox::default(),
+            ))
+            .id();
+
+        set_border_radius(&mut world, entity, BorderRadius::all(Val::Px(8.0)));
+        // Bevy's `extract_ui_material_nodes` only extracts nodes without a `BackgroundColor`
+        let handle = world
+            .query_filtered::<&Handle<RoundedBoxMaterial>, Without<BackgroundColor>>()
+            .get(&world, entity)
+            .unwrap()
+            .clone();
+        let material = world
+            .resource::<Assets<RoundedBoxMaterial>>()
+            .get(&handle)
+            .unwrap();
+        assert_eq!(
+            material.rounded_box.background_color,
+            Vec4::from(Color::RED.as_linear_rgba_f32())
+        );
+
+        set_border_radius(&mut world, entity, BorderRadius::default());
+        assert_eq!(
+            world.get::<BackgroundColor>(entity).map(|color| color.0),
+            Some(Color::RED)
+        );
+        assert!(world.get::<Handle<RoundedBoxMaterial>>(entity).is_none());
+    }
+}
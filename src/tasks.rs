@@ -0,0 +1,48 @@
+use bevy::tasks::{Task, TaskPool};
+use dioxus::prelude::{spawn, use_drop, use_hook, use_signal, ReadOnlySignal, Writable};
+use std::{
+    cell::RefCell,
+    future::{poll_fn, Future},
+    pin::Pin,
+    rc::Rc,
+    task::Poll,
+};
+
+/// Runs `task` on a Bevy task pool, such as `AsyncComputeTaskPool::get()` for CPU-heavy work or
+/// `IoTaskPool::get()` for file access, returning `None` until it completes.
+///
+/// The task is only started on the first render, so it isn't restarted when anything it captures
+/// changes; give the component a `key` to start it over. It is cancelled if the component is
+/// dropped before it completes. Tasks that can fail should return a `Result`.
+///
+/// ```ignore
+/// let scores = use_task(AsyncComputeTaskPool::get(), || async { parse_leaderboard("scores.csv") });
+/// match &*scores.read() {
+///     None => rsx! { text { text: "Loading..." } },
+///     Some(Ok(scores)) => rsx! { Leaderboard { scores: scores.clone() } },
+///     Some(Err(error)) => rsx! { text { text: "{error}" } },
+/// }
+/// ```
+pub fn use_task<T, F>(task_pool: &TaskPool, task: impl FnOnce() -> F) -> ReadOnlySignal<Option<T>>
+where
+    T: Send + 'static,
+    F: Future<Output = T> + Send + 'static,
+{
+    let mut output = use_signal(|| None);
+    let task: Rc<RefCell<Option<Task<T>>>> = use_hook(|| {
+        let task = Rc::new(RefCell::new(Some(task_pool.spawn(task()))));
+        let running_task = Rc::clone(&task);
+        spawn(async move {
+            let value = poll_fn(|cx| match &mut *running_task.borrow_mut() {
+                Some(task) => Pin::new(task).poll(cx),
+                None => Poll::Pending,
+            })
+            .await;
+            output.set(Some(value));
+        });
+        task
+    });
+    // Dropping a Bevy task cancels it
+    use_drop(move || drop(task.borrow_mut().take()));
+    ReadOnlySignal::new(output)
+}
@@ -1,7 +1,8 @@
 use crate::{
     apply_mutations::MutationApplier,
+    deferred_commands::DeferredCommandQueue,
     deferred_system::DeferredSystemRunQueue,
-    ecs_hooks::EcsContext,
+    ecs_hooks::{with_world_access, with_world_read_access},
     events::{bubble_event, EventReaders},
     DioxusUiRoot, UiContext, UiRoot,
 };
@@ -27,6 +28,8 @@ pub fn tick_dioxus_ui(world: &mut World) {
             world.resource(),
             world.resource(),
             world.resource(),
+            world.resource(),
+            world.resource(),
         )
     });
 
@@ -53,12 +56,23 @@ pub fn tick_dioxus_ui(world: &mut World) {
             .roots
             .insert((root_entity, dioxus_ui_root), ui_root);
     }
+
+    world
+        .resource::<DeferredCommandQueue>()
+        .clone()
+        .apply(world);
 }
 
 fn run_deferred_systems(world: &mut World) {
-    for mut system in mem::take(&mut *world.resource_mut::<DeferredSystemRunQueue>().run_queue) {
-        system.initialize(world);
-        system.run((), world);
+    let run_queue = mem::take(
+        &mut *world
+            .resource::<DeferredSystemRunQueue>()
+            .run_queue
+            .lock()
+            .unwrap(),
+    );
+    for run in run_queue {
+        run(world);
     }
 }
 
@@ -67,26 +81,39 @@ fn dispatch_ui_events(
     ui_root: &mut UiRoot,
     world: &World,
 ) {
-    for (mut target, name, data, bubbles) in events {
-        if *bubbles {
-            bubble_event(name, &mut target, world);
-        }
-        if let Some(target_element_id) = ui_root.bevy_ui_entity_to_element_id.get(&target) {
-            ui_root
-                .virtual_dom
-                .handle_event(name, Rc::clone(data), *target_element_id, *bubbles);
+    // Handlers can read the world through `EcsRef`s as of the time of the event
+    with_world_read_access(world, || {
+        for (mut target, name, data, bubbles) in events {
+            if *bubbles {
+                bubble_event(name, &mut target, world);
+            }
+            if let Some(target_element_id) = ui_root.bevy_ui_entity_to_element_id.get(&target) {
+                ui_root.virtual_dom.handle_event(
+                    name,
+                    Rc::clone(data),
+                    *target_element_id,
+                    *bubbles,
+                );
+            }
         }
-    }
+    });
 }
 
 fn schedule_ui_renders_from_ecs_subscriptions(ui_root: &mut UiRoot, world: &World) {
-    let ecs_subscriptions = &world.non_send_resource::<UiContext>().subscriptions;
+    let ecs_subscriptions = world
+        .non_send_resource::<UiContext>()
+        .subscriptions
+        .borrow();
 
-    for scope_id in &*ecs_subscriptions.world_and_queries {
+    for scope_id in &ecs_subscriptions.world_and_queries {
         ui_root.virtual_dom.mark_dirty(*scope_id);
     }
 
-    for (resource_id, scope_ids) in &*ecs_subscriptions.resources {
+    for scope_id in &ecs_subscriptions.loading_assets {
+        ui_root.virtual_dom.mark_dirty(*scope_id);
+    }
+
+    for (resource_id, scope_ids) in &ecs_subscriptions.resources {
         if world.is_resource_changed_by_id(*resource_id) {
             for scope_id in scope_ids {
                 ui_root.virtual_dom.mark_dirty(*scope_id);
@@ -104,37 +131,30 @@ fn schedule_ui_renders_from_ecs_subscriptions(ui_root: &mut UiRoot, world: &Worl
 }
 
 fn render_ui(root_entity: Entity, ui_root: &mut UiRoot, world: &mut World) {
-    ui_root
-        .virtual_dom
-        .provide_root_context(EcsContext { world });
-
     #[cfg(feature = "hot_reload")]
     crate::hot_reload::update_templates(world, &mut ui_root.virtual_dom);
 
-    if ui_root.needs_rebuild {
-        world.resource_scope(|world, asset_server: Mut<AssetServer>| {
-            let mut mutation_applier = MutationApplier::new(
-                &mut ui_root.element_id_to_bevy_ui_entity,
-                &mut ui_root.bevy_ui_entity_to_element_id,
-                &mut ui_root.templates,
-                root_entity,
-                world,
-                &asset_server,
-            );
-            ui_root.virtual_dom.rebuild(&mut mutation_applier);
-        });
-        ui_root.needs_rebuild = false;
-    }
+    // Not taken out of the world, since components can still use it through hooks
+    let asset_server = world.resource::<AssetServer>().clone();
+
+    // The world is only lent out, so that components and the mutation applier can each borrow it
+    // in turn while the `VirtualDom` renders
+    with_world_access(world, || {
+        // Polls the tasks that were woken since the last tick, without waiting for any, so that
+        // `spawn`, `use_future` and `use_resource` make progress and mark their scopes dirty
+        ui_root.virtual_dom.process_events();
 
-    world.resource_scope(|world, asset_server: Mut<AssetServer>| {
         let mut mutation_applier = MutationApplier::new(
             &mut ui_root.element_id_to_bevy_ui_entity,
             &mut ui_root.bevy_ui_entity_to_element_id,
             &mut ui_root.templates,
             root_entity,
-            world,
             &asset_server,
         );
+        if ui_root.needs_rebuild {
+            ui_root.virtual_dom.rebuild(&mut mutation_applier);
+            ui_root.needs_rebuild = false;
+        }
         ui_root.virtual_dom.render_immediate(&mut mutation_applier);
     });
 }
@@ -0,0 +1,50 @@
+use bevy::{
+    app::App,
+    asset::{Assets, Handle},
+    ecs::{component::Component, system::Resource, world::EntityWorldMut},
+    prelude::{Deref, DerefMut},
+    ui::{UiMaterial, UiMaterialPlugin},
+    utils::HashMap,
+};
+use std::{any::TypeId, hash::Hash};
+
+/// A [`UiMaterial`] registered with [`crate::DioxusUiPlugin::with_ui_material`], and so usable by
+/// `material_node` elements.
+#[derive(Clone, Copy)]
+pub struct UiMaterialType {
+    pub add_plugin: fn(&mut App),
+    /// Inserts a new default material, so that every element can change its own material.
+    pub spawn: fn(&mut EntityWorldMut),
+    pub handle_type_id: TypeId,
+}
+
+impl UiMaterialType {
+    pub fn new<M: UiMaterial + Default>() -> Self
+    where
+        M::Data: PartialEq + Eq + Hash + Clone,
+    {
+        Self {
+            add_plugin: |app| {
+                if !app.is_plugin_added::<UiMaterialPlugin<M>>() {
+                    app.add_plugins(UiMaterialPlugin::<M>::default());
+                }
+            },
+            spawn: |entity| {
+                let material = entity.world_scope(|world| {
+                    Some(world.get_resource_mut::<Assets<M>>()?.add(M::default()))
+                });
+                // Without the render plugin, the element is only laid out
+                if let Some(material) = material {
+                    entity.insert(material);
+                }
+            },
+            handle_type_id: TypeId::of::<Handle<M>>(),
+        }
+    }
+}
+
+#[derive(Resource, Deref, DerefMut, Default, Clone)]
+pub struct UiMaterials(pub HashMap<&'static str, UiMaterialType>);
+
+#[derive(Component)]
+pub struct UiMaterialTag(pub &'static str);
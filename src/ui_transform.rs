@@ -0,0 +1,89 @@
+use bevy::{
+    ecs::{
+        change_detection::{DetectChanges, DetectChangesMut, Ref},
+        component::Component,
+        query::With,
+        system::Query,
+    },
+    math::{Quat, Vec2},
+    transform::components::Transform,
+    ui::{Node, Val},
+    window::{PrimaryWindow, Window},
+};
+
+/// A 2D transform applied on top of an element's layout, like CSS transforms.
+///
+/// Bevy's layout system owns `Transform::translation`, so the translation is applied as an offset
+/// from the layout position right after layout, whenever either of them changes.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct UiTransform {
+    /// Percentages are relative to the element's own size.
+    pub translation: (Val, Val),
+    /// Clockwise, in radians.
+    pub rotation: f32,
+    pub scale: Vec2,
+    /// The point rotation and scale are applied around. Percentages are relative to the
+    /// element's own size, and pixels are from its top left corner.
+    pub origin: (Val, Val),
+    /// The translation written by the layout system, captured whenever it changes.
+    layout_position: Vec2,
+}
+
+impl Default for UiTransform {
+    fn default() -> Self {
+        Self {
+            translation: (Val::Px(0.0), Val::Px(0.0)),
+            rotation: 0.0,
+            scale: Vec2::ONE,
+            origin: (Val::Percent(50.0), Val::Percent(50.0)),
+            layout_position: Vec2::ZERO,
+        }
+    }
+}
+
+pub fn apply_ui_transforms(
+    mut nodes: Query<(Ref<Node>, &mut UiTransform, &mut Transform)>,
+    primary_window: Query<Ref<Window>, With<PrimaryWindow>>,
+) {
+    let (viewport_size, viewport_changed) = primary_window
+        .get_single()
+        .map(|window| {
+            (
+                Vec2::new(window.width(), window.height()),
+                window.is_changed(),
+            )
+        })
+        .unwrap_or_default();
+    let resolve = |val: Val, size: f32| val.resolve(size, viewport_size).unwrap_or(0.0);
+
+    for (node, mut ui_transform, mut transform) in &mut nodes {
+        // Our own writes don't count as changes here, so a changed `Transform` was just written by
+        // the layout system, and an unchanged one still holds the offset from the last time
+        if transform.is_changed() {
+            ui_transform.bypass_change_detection().layout_position =
+                transform.translation.truncate();
+        } else if !(node.is_changed() || ui_transform.is_changed() || viewport_changed) {
+            continue;
+        }
+        let layout_position = ui_transform.layout_position;
+
+        let size = node.size();
+        let rotation = Quat::from_rotation_z(ui_transform.rotation);
+        let scale = ui_transform.scale.extend(1.0);
+        let translation = Vec2::new(
+            resolve(ui_transform.translation.0, size.x),
+            resolve(ui_transform.translation.1, size.y),
+        );
+        let origin = Vec2::new(
+            resolve(ui_transform.origin.0, size.x),
+            resolve(ui_transform.origin.1, size.y),
+        ) - size / 2.0;
+        let offset = translation + origin - (rotation * (scale * origin.extend(0.0))).truncate();
+
+        transform.set_if_neq(Transform {
+            translation: (layout_position + offset).extend(transform.translation.z),
+            rotation,
+            scale,
+        });
+    }
+}